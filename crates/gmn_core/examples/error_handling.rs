@@ -56,9 +56,13 @@ fn demonstrate_tracing_error() {
 fn demonstrate_database_error() {
 	info!("=== Database Error Example ===");
 
-	let error: GmnError = DatabaseError::ConnectionFailed(
-		"Failed to connect to database at localhost:5432".to_string(),
-	)
+	let error: GmnError = DatabaseError::ConnectionFailed {
+		message: "Failed to connect to database at localhost:5432".to_string(),
+		source: Some(Box::new(std::io::Error::new(
+			std::io::ErrorKind::ConnectionRefused,
+			"connection refused",
+		))),
+	}
 	.into();
 
 	display_error(&error);
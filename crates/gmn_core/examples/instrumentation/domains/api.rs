@@ -2,7 +2,8 @@
 //!
 //! This module provides tracing helpers for API operations.
 
-use tracing::Span;
+use std::error::Error;
+use tracing::{Level, Span};
 
 /// HTTP methods
 #[derive(Debug, Clone, Copy)]
@@ -44,6 +45,94 @@ impl HttpMethod {
 	}
 }
 
+/// Canonical gRPC status codes, with their numeric values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrpcStatus {
+	/// Success
+	Ok = 0,
+	/// Cancelled by the caller
+	Cancelled = 1,
+	/// Unknown error
+	Unknown = 2,
+	/// Client sent an invalid argument
+	InvalidArgument = 3,
+	/// Deadline expired before completion
+	DeadlineExceeded = 4,
+	/// Requested entity not found
+	NotFound = 5,
+	/// Entity already exists
+	AlreadyExists = 6,
+	/// Caller lacks permission
+	PermissionDenied = 7,
+	/// Quota or resource exhausted
+	ResourceExhausted = 8,
+	/// System not in the required state
+	FailedPrecondition = 9,
+	/// Operation aborted (e.g. concurrency conflict)
+	Aborted = 10,
+	/// Operation past the valid range
+	OutOfRange = 11,
+	/// Operation not implemented
+	Unimplemented = 12,
+	/// Internal server error
+	Internal = 13,
+	/// Service currently unavailable
+	Unavailable = 14,
+	/// Unrecoverable data loss
+	DataLoss = 15,
+	/// Missing or invalid credentials
+	Unauthenticated = 16,
+}
+
+impl GrpcStatus {
+	/// Look up the status for a numeric gRPC code
+	pub const fn from_code(code: i32) -> Option<Self> {
+		Some(match code {
+			0 => Self::Ok,
+			1 => Self::Cancelled,
+			2 => Self::Unknown,
+			3 => Self::InvalidArgument,
+			4 => Self::DeadlineExceeded,
+			5 => Self::NotFound,
+			6 => Self::AlreadyExists,
+			7 => Self::PermissionDenied,
+			8 => Self::ResourceExhausted,
+			9 => Self::FailedPrecondition,
+			10 => Self::Aborted,
+			11 => Self::OutOfRange,
+			12 => Self::Unimplemented,
+			13 => Self::Internal,
+			14 => Self::Unavailable,
+			15 => Self::DataLoss,
+			16 => Self::Unauthenticated,
+			_ => return None,
+		})
+	}
+
+	/// Get the canonical name of the status
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Ok => "OK",
+			Self::Cancelled => "Cancelled",
+			Self::Unknown => "Unknown",
+			Self::InvalidArgument => "InvalidArgument",
+			Self::DeadlineExceeded => "DeadlineExceeded",
+			Self::NotFound => "NotFound",
+			Self::AlreadyExists => "AlreadyExists",
+			Self::PermissionDenied => "PermissionDenied",
+			Self::ResourceExhausted => "ResourceExhausted",
+			Self::FailedPrecondition => "FailedPrecondition",
+			Self::Aborted => "Aborted",
+			Self::OutOfRange => "OutOfRange",
+			Self::Unimplemented => "Unimplemented",
+			Self::Internal => "Internal",
+			Self::Unavailable => "Unavailable",
+			Self::DataLoss => "DataLoss",
+			Self::Unauthenticated => "Unauthenticated",
+		}
+	}
+}
+
 /// Create a span for an API request
 #[allow(dead_code)]
 pub fn request_span(method: HttpMethod, path: &str) -> Span {
@@ -54,6 +143,7 @@ pub fn request_span(method: HttpMethod, path: &str) -> Span {
 		status_code = tracing::field::Empty,
 		duration_ms = tracing::field::Empty,
 		request_id = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -66,6 +156,7 @@ pub fn request_span_with_id(method: HttpMethod, path: &str, request_id: &str) ->
 		request_id = request_id,
 		status_code = tracing::field::Empty,
 		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -77,17 +168,62 @@ pub fn external_api_span(service: &str, endpoint: &str) -> Span {
 		endpoint = endpoint,
 		status_code = tracing::field::Empty,
 		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
+	)
+}
+
+/// Create a span for a gRPC call
+pub fn grpc_span(service: &str, method: &str) -> Span {
+	tracing::info_span!(
+		"grpc_request",
+		service = service,
+		method = method,
+		status_code = tracing::field::Empty,
+		status = tracing::field::Empty,
+		duration_ms = tracing::field::Empty,
+		request_id = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
 /// Record API request completion
+///
+/// Works for both [`request_span`] and [`external_api_span`]. A 4xx or 5xx status marks
+/// the span with `error = true` and emits a `WARN` or `ERROR` event inside it, so
+/// level-based filters and alerts catch failed requests.
 pub fn record_request_completion(span: &Span, status_code: u16, duration_ms: u64) {
 	span.record("status_code", status_code);
 	span.record("duration_ms", duration_ms);
+
+	if status_code >= 500 {
+		span.record("error", true);
+		tracing::event!(parent: span, Level::ERROR, status_code, duration_ms, "request failed");
+	} else if status_code >= 400 {
+		span.record("error", true);
+		tracing::event!(parent: span, Level::WARN, status_code, duration_ms, "request rejected");
+	}
 }
 
-/// Record request ID
+/// Record gRPC call completion
+///
+/// `code` is the numeric gRPC status; its canonical name is recorded as `status`.
+pub fn record_grpc_completion(span: &Span, code: i32, duration_ms: u64) {
+	span.record("status_code", code);
+	span.record("status", GrpcStatus::from_code(code).map_or("Unknown", |status| status.as_str()));
+	span.record("duration_ms", duration_ms);
+}
+
+/// Record a request that failed without a response (timeout, connection reset, ...)
+///
+/// Works for HTTP, gRPC and external API spans. Marks the span with `error = true` and
+/// emits an `ERROR` event inside it.
 #[allow(dead_code)]
+pub fn record_request_error(span: &Span, err: &dyn Error) {
+	span.record("error", true);
+	tracing::event!(parent: span, Level::ERROR, error = %err, "Request failed");
+}
+
+/// Record request ID
 pub fn record_request_id(span: &Span, request_id: &str) {
 	span.record("request_id", request_id);
 }
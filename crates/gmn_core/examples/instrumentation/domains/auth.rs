@@ -2,7 +2,8 @@
 //!
 //! This module provides tracing helpers for authentication and session management.
 
-use tracing::Span;
+use std::error::Error;
+use tracing::{Level, Span};
 
 /// Authentication operation types
 #[derive(Debug, Clone, Copy)]
@@ -57,6 +58,7 @@ pub fn auth_span(operation: AuthOperation, user_id: Option<&str>) -> Span {
 			user_id = uid,
 			success = tracing::field::Empty,
 			duration_ms = tracing::field::Empty,
+			error = tracing::field::Empty,
 		)
 	} else {
 		tracing::info_span!(
@@ -64,6 +66,7 @@ pub fn auth_span(operation: AuthOperation, user_id: Option<&str>) -> Span {
 			operation = operation.as_str(),
 			success = tracing::field::Empty,
 			duration_ms = tracing::field::Empty,
+			error = tracing::field::Empty,
 		)
 	}
 }
@@ -77,6 +80,7 @@ pub fn session_span(operation: AuthOperation, session_id: &str) -> Span {
 		session_id = session_id,
 		success = tracing::field::Empty,
 		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -87,6 +91,7 @@ pub fn api_key_span(key_prefix: &str) -> Span {
 		key_prefix = key_prefix,
 		valid = tracing::field::Empty,
 		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -96,6 +101,17 @@ pub fn record_auth_result(span: &Span, success: bool, duration_ms: u64) {
 	span.record("duration_ms", duration_ms);
 }
 
+/// Record a failed authentication, session or API key operation
+///
+/// Marks the span with `error = true` and `success = false`, and emits a `WARN` event
+/// inside it: rejected credentials are expected traffic, not a fault of the service.
+#[allow(dead_code)]
+pub fn record_auth_error(span: &Span, err: &dyn Error) {
+	span.record("error", true);
+	span.record("success", false);
+	tracing::event!(parent: span, Level::WARN, error = %err, "Authentication failed");
+}
+
 /// Record API key validation result
 pub fn record_api_key_result(span: &Span, valid: bool, duration_ms: u64) {
 	span.record("valid", valid);
@@ -0,0 +1,69 @@
+//! Cache operation tracing utilities.
+//!
+//! This module provides tracing helpers for cache operations (Redis, in-memory, ...).
+
+use std::error::Error;
+use tracing::{Level, Span};
+
+/// Cache operation types
+#[derive(Debug, Clone, Copy)]
+pub enum CacheOperation {
+	/// Read a key
+	Get,
+	/// Write a key
+	Set,
+	/// Remove a key
+	#[allow(dead_code)]
+	Delete,
+	/// Key evicted to free space
+	#[allow(dead_code)]
+	Evict,
+	/// Key expired by its TTL
+	#[allow(dead_code)]
+	Expire,
+}
+
+impl CacheOperation {
+	/// Get the string representation of the operation
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Get => "GET",
+			Self::Set => "SET",
+			Self::Delete => "DELETE",
+			Self::Evict => "EVICT",
+			Self::Expire => "EXPIRE",
+		}
+	}
+}
+
+/// Create a span for a cache operation
+///
+/// Only the key prefix (e.g. `"user:"`) is recorded, so span cardinality stays low
+/// and no full keys end up in the logs.
+pub fn cache_span(operation: CacheOperation, key_prefix: &str) -> Span {
+	tracing::info_span!(
+		"cache_operation",
+		operation = operation.as_str(),
+		key_prefix = key_prefix,
+		hit = tracing::field::Empty,
+		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
+	)
+}
+
+/// Record cache operation result
+pub fn record_cache_result(span: &Span, hit: bool, duration_ms: u64) {
+	span.record("hit", hit);
+	span.record("duration_ms", duration_ms);
+}
+
+/// Record a failed cache operation (connection refused, timeout, ...)
+///
+/// Marks the span with `error = true` and emits a `WARN` event inside it: callers
+/// usually fall back to the source of truth, so a cache failure degrades rather than
+/// breaks the request.
+#[allow(dead_code)]
+pub fn record_cache_error(span: &Span, err: &dyn Error) {
+	span.record("error", true);
+	tracing::event!(parent: span, Level::WARN, error = %err, "Cache operation failed");
+}
@@ -2,7 +2,8 @@
 //!
 //! This module provides tracing helpers specifically for database operations.
 
-use tracing::Span;
+use std::error::Error;
+use tracing::{Level, Span};
 
 /// Database operation types
 #[derive(Debug, Clone, Copy)]
@@ -56,6 +57,7 @@ pub fn query_span(operation: DbOperation, table: &str) -> Span {
 		table = table,
 		rows_affected = tracing::field::Empty,
 		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -66,6 +68,7 @@ pub fn transaction_span(operation: DbOperation) -> Span {
 		"db_transaction",
 		operation = operation.as_str(),
 		duration_ms = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -77,6 +80,7 @@ pub fn pool_span(operation: &str) -> Span {
 		operation = operation,
 		active_connections = tracing::field::Empty,
 		idle_connections = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -86,6 +90,14 @@ pub fn record_query_metrics(span: &Span, rows_affected: u64, duration_ms: u64) {
 	span.record("duration_ms", duration_ms);
 }
 
+/// Record a failed query, transaction or pool operation
+///
+/// Marks the span with `error = true` and emits an `ERROR` event inside it.
+pub fn record_query_error(span: &Span, err: &dyn Error) {
+	span.record("error", true);
+	tracing::event!(parent: span, Level::ERROR, error = %err, "Database operation failed");
+}
+
 /// Record connection pool metrics
 #[allow(dead_code)]
 pub fn record_pool_metrics(span: &Span, active: u32, idle: u32) {
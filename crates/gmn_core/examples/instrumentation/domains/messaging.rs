@@ -0,0 +1,89 @@
+//! Message queue and event tracing utilities.
+//!
+//! This module provides tracing helpers for publishing to and consuming from
+//! message brokers such as Kafka or NATS.
+
+use std::error::Error;
+use tracing::{Level, Span};
+
+/// Messaging operation types
+#[derive(Debug, Clone, Copy)]
+pub enum MessagingOperation {
+	/// Send a message to a topic
+	Publish,
+	/// Receive a message from a topic
+	Consume,
+	/// Acknowledge a processed message
+	#[allow(dead_code)]
+	Ack,
+	/// Reject a message for redelivery
+	#[allow(dead_code)]
+	Nack,
+}
+
+impl MessagingOperation {
+	/// Get the string representation of the operation
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Publish => "publish",
+			Self::Consume => "consume",
+			Self::Ack => "ack",
+			Self::Nack => "nack",
+		}
+	}
+}
+
+/// Create a span for a messaging operation
+///
+/// `partition` is only recorded for brokers that have them (Kafka, not NATS).
+pub fn messaging_span(operation: MessagingOperation, topic: &str, partition: Option<i32>) -> Span {
+	partition.map_or_else(
+		|| {
+			tracing::info_span!(
+				"messaging_operation",
+				operation = operation.as_str(),
+				topic = topic,
+				message_id = tracing::field::Empty,
+				lag = tracing::field::Empty,
+				duration_ms = tracing::field::Empty,
+				error = tracing::field::Empty,
+			)
+		},
+		|partition| {
+			tracing::info_span!(
+				"messaging_operation",
+				operation = operation.as_str(),
+				topic = topic,
+				partition = partition,
+				message_id = tracing::field::Empty,
+				lag = tracing::field::Empty,
+				duration_ms = tracing::field::Empty,
+				error = tracing::field::Empty,
+			)
+		},
+	)
+}
+
+/// Record the result of publishing a message
+pub fn record_publish_result(span: &Span, message_id: &str, duration_ms: u64) {
+	span.record("message_id", message_id);
+	span.record("duration_ms", duration_ms);
+}
+
+/// Record the result of consuming a message
+///
+/// `lag` is how many messages the consumer is behind the head of the partition.
+pub fn record_consume_result(span: &Span, message_id: &str, lag: u64, duration_ms: u64) {
+	span.record("message_id", message_id);
+	span.record("lag", lag);
+	span.record("duration_ms", duration_ms);
+}
+
+/// Record a failed publish, consume or acknowledgement
+///
+/// Marks the span with `error = true` and emits an `ERROR` event inside it.
+#[allow(dead_code)]
+pub fn record_messaging_error(span: &Span, err: &dyn Error) {
+	span.record("error", true);
+	tracing::event!(parent: span, Level::ERROR, error = %err, "Messaging operation failed");
+}
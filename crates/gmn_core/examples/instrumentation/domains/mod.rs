@@ -5,5 +5,7 @@
 
 pub mod api;
 pub mod auth;
+pub mod cache;
 pub mod database;
+pub mod messaging;
 pub mod rate_limit;
@@ -1,8 +1,20 @@
 //! Rate limiting tracing utilities.
 //!
 //! This module provides tracing helpers for rate limiting operations.
+//!
+//! With the `metrics` feature, the helpers also report through the `metrics` facade
+//! (install any recorder, e.g. a Prometheus exporter, to collect them):
+//!
+//! | Metric                      | Kind    | Labels     | Emitted by                  |
+//! |-----------------------------|---------|------------|-----------------------------|
+//! | `gmn.rate_limit.exceeded`   | counter | `resource` | [`log_rate_limit_exceeded`] |
+//! | `gmn.rate_limit.remaining`  | gauge   | `resource` | [`record_check_result`]     |
+//!
+//! The caller `identifier` is deliberately not a label, to keep cardinality bounded.
+//! Tracing output is the same with or without the feature.
 
-use tracing::Span;
+use std::error::Error;
+use tracing::{Level, Span};
 
 /// Rate limit check result
 #[derive(Debug, Clone, Copy)]
@@ -39,6 +51,7 @@ pub fn rate_limit_check_span(resource: &str, identifier: &str) -> Span {
 		limit = tracing::field::Empty,
 		window_secs = tracing::field::Empty,
 		remaining = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
@@ -50,12 +63,14 @@ pub fn rate_limit_config_span(resource: &str) -> Span {
 		resource = resource,
 		limit = tracing::field::Empty,
 		window_secs = tracing::field::Empty,
+		error = tracing::field::Empty,
 	)
 }
 
 /// Record rate limit check result
 pub fn record_check_result(
 	span: &Span,
+	resource: &str,
 	result: RateLimitResult,
 	requests: u32,
 	limit: u32,
@@ -67,6 +82,11 @@ pub fn record_check_result(
 	span.record("limit", limit);
 	span.record("window_secs", window_secs);
 	span.record("remaining", remaining);
+
+	#[cfg(feature = "metrics")]
+	metrics::gauge!("gmn.rate_limit.remaining", "resource" => resource.to_string()).set(remaining);
+	#[cfg(not(feature = "metrics"))]
+	let _ = resource;
 }
 
 /// Record rate limit configuration
@@ -76,6 +96,17 @@ pub fn record_config(span: &Span, limit: u32, window_secs: u64) {
 	span.record("window_secs", window_secs);
 }
 
+/// Record a rate limit check or configuration that could not be completed
+///
+/// For failures of the limiter itself (e.g. its backing store is unreachable), not for
+/// denied requests. Marks the span with `error = true` and emits an `ERROR` event
+/// inside it.
+#[allow(dead_code)]
+pub fn record_rate_limit_error(span: &Span, err: &dyn Error) {
+	span.record("error", true);
+	tracing::event!(parent: span, Level::ERROR, error = %err, "Rate limit check failed");
+}
+
 /// Log a rate limit violation event
 pub fn log_rate_limit_exceeded(resource: &str, identifier: &str, requests: u32, limit: u32) {
 	tracing::warn!(
@@ -85,4 +116,7 @@ pub fn log_rate_limit_exceeded(resource: &str, identifier: &str, requests: u32,
 		limit = limit,
 		"Rate limit exceeded"
 	);
+
+	#[cfg(feature = "metrics")]
+	metrics::counter!("gmn.rate_limit.exceeded", "resource" => resource.to_string()).increment(1);
 }
@@ -10,7 +10,7 @@
 
 use gmn_core::prelude::*;
 mod domains;
-use domains::{api, auth, database, rate_limit};
+use domains::{api, auth, cache, database, messaging, rate_limit};
 use gmn_core::tracing::TracingConfig;
 use std::thread;
 use std::time::Duration;
@@ -19,7 +19,7 @@ use std::time::Instant;
 fn main() -> Result<()> {
 	// Initialize with development config for better visibility
 	let config = TracingConfig::development();
-	init_tracing_with_config(config)?;
+	let _tracing = init_tracing_with_config(config)?;
 
 	info!("Starting advanced instrumentation demo");
 
@@ -35,6 +35,12 @@ fn main() -> Result<()> {
 	// Rate limiting
 	demonstrate_rate_limit_tracing();
 
+	// Cache operations
+	demonstrate_cache_tracing();
+
+	// Message queue operations
+	demonstrate_messaging_tracing();
+
 	// Performance measurement
 	demonstrate_performance_measurement();
 
@@ -55,6 +61,14 @@ fn demonstrate_database_tracing() {
 	// Record metrics
 	database::record_query_metrics(&span, 42, 50);
 	info!("Query returned 42 rows in 50ms");
+
+	// Failed query
+	drop(_guard);
+	let failed_span = database::query_span(database::DbOperation::Select, "orders");
+	let _failed_guard = failed_span.enter();
+
+	let err = std::io::Error::new(std::io::ErrorKind::TimedOut, "statement timeout");
+	database::record_query_error(&failed_span, &err);
 }
 
 fn demonstrate_api_tracing() {
@@ -78,7 +92,19 @@ fn demonstrate_api_tracing() {
 
 	info!("Calling external GitHub API");
 	thread::sleep(Duration::from_millis(200));
-	api::record_request_completion(&ext_span, 200, 200);
+
+	// A 5xx marks the span as errored and logs at ERROR
+	api::record_request_completion(&ext_span, 503, 200);
+
+	// gRPC call
+	drop(_ext_guard);
+	let grpc_span = api::grpc_span("users.v1.UserService", "GetUser");
+	let _grpc_guard = grpc_span.enter();
+	api::record_request_id(&grpc_span, "req-12346");
+
+	info!("Handling gRPC request");
+	thread::sleep(Duration::from_millis(20));
+	api::record_grpc_completion(&grpc_span, api::GrpcStatus::NotFound as i32, 20);
 }
 
 fn demonstrate_auth_tracing() {
@@ -115,6 +141,7 @@ fn demonstrate_rate_limit_tracing() {
 
 	rate_limit::record_check_result(
 		&span,
+		"api_requests",
 		rate_limit::RateLimitResult::Allowed,
 		45,  // requests
 		100, // limit
@@ -128,6 +155,49 @@ fn demonstrate_rate_limit_tracing() {
 	rate_limit::log_rate_limit_exceeded("api_requests", "user-456", 101, 100);
 }
 
+fn demonstrate_cache_tracing() {
+	info!("=== Cache Tracing Demo ===");
+
+	// Cache miss, then fill
+	let span = cache::cache_span(cache::CacheOperation::Get, "user:");
+	let _guard = span.enter();
+
+	info!("Looking up cached user profile");
+	thread::sleep(Duration::from_millis(2));
+	cache::record_cache_result(&span, false, 2);
+	info!("Cache miss");
+
+	drop(_guard);
+	let set_span = cache::cache_span(cache::CacheOperation::Set, "user:");
+	let _set_guard = set_span.enter();
+
+	info!("Storing user profile");
+	thread::sleep(Duration::from_millis(3));
+	cache::record_cache_result(&set_span, false, 3);
+}
+
+fn demonstrate_messaging_tracing() {
+	info!("=== Messaging Tracing Demo ===");
+
+	// Publish an event
+	let span = messaging::messaging_span(messaging::MessagingOperation::Publish, "orders", Some(3));
+	let _guard = span.enter();
+
+	info!("Publishing order event");
+	thread::sleep(Duration::from_millis(10));
+	messaging::record_publish_result(&span, "msg-0001", 10);
+
+	// Consume it on the other side
+	drop(_guard);
+	let consume_span =
+		messaging::messaging_span(messaging::MessagingOperation::Consume, "orders", Some(3));
+	let _consume_guard = consume_span.enter();
+
+	info!("Consuming order event");
+	thread::sleep(Duration::from_millis(5));
+	messaging::record_consume_result(&consume_span, "msg-0001", 12, 5);
+}
+
 fn demonstrate_performance_measurement() {
 	info!("=== Performance Measurement Demo ===");
 
@@ -140,6 +210,13 @@ fn demonstrate_performance_measurement() {
 
 	info!(result = result, "Computation complete");
 
+	// Keeping the elapsed time, e.g. to record it on a span
+	let (rows, elapsed) = measure_duration_timed!("load_report", {
+		thread::sleep(Duration::from_millis(30));
+		7
+	});
+	info!(rows = rows, elapsed_ms = elapsed.as_millis(), "Report loaded");
+
 	// Manual measurement with span
 	let span = trace_operation!("manual_measurement");
 	let _guard = span.enter();
@@ -5,9 +5,20 @@
 //!
 //! All display functions automatically emit corresponding tracing events so that
 //! user-visible output is also captured in logs for observability.
+//!
+//! Embedders can receive the rendered frames through a callback instead of stderr by
+//! installing one with [`set_sink`].
+//!
+//! When running under GitHub Actions (`GITHUB_ACTIONS=true`), errors and warnings are
+//! additionally written to stderr as workflow-command annotations so they show up
+//! inline on the pull request diff. Stdout is never written to.
 
 use crate::errors::GmnError;
-use crate::print_pretty_error::{PrettyMessageLevel, print_pretty_message};
+use crate::print_pretty_error::{MessageParts, PrettyMessageLevel, print_message_parts};
+use colored::Colorize;
+use std::io::Write;
+
+pub use crate::print_pretty_error::{PrettySink, clear_sink, set_sink};
 
 /// Shared metadata for displayable diagnostics/messages.
 ///
@@ -33,6 +44,13 @@ pub trait DisplayMetadata {
 	fn hint(&self) -> Option<&str> {
 		None
 	}
+
+	/// Messages of the underlying causes, outermost first.
+	///
+	/// Each one is drawn as a `caused by:` line under the message.
+	fn causes(&self) -> Vec<String> {
+		Vec::new()
+	}
 }
 
 /// Lightweight generic display payload for warnings/info/success and ad-hoc errors.
@@ -83,6 +101,7 @@ impl DisplayMetadata for GmnError {
 			GmnError::Auth(_) => "Authentication Error",
 			GmnError::RateLimit(_) => "Rate Limit Exceeded",
 			GmnError::Api(_) => "API Error",
+			GmnError::Io(_) => "I/O Error",
 			GmnError::Internal(_) => "Internal Error",
 		}
 	}
@@ -102,6 +121,10 @@ impl DisplayMetadata for GmnError {
 	fn hint(&self) -> Option<&str> {
 		self.hint()
 	}
+
+	fn causes(&self) -> Vec<String> {
+		self.flatten().into_iter().skip(1).map(|(_, message)| message).collect()
+	}
 }
 
 /// Generic adapter for any standard error where no richer metadata exists.
@@ -175,26 +198,47 @@ impl<'a, E: std::error::Error + ?Sized> DisplayMetadata for GenericErrorDisplay<
 	fn hint(&self) -> Option<&str> {
 		self.hint
 	}
+
+	fn causes(&self) -> Vec<String> {
+		std::iter::successors(self.error.source(), |err| err.source())
+			.map(ToString::to_string)
+			.collect()
+	}
 }
 
+/// Emit the tracing event for a displayed message, then draw the pretty frame.
+///
+/// Optional fields (`context`, `hint`, `command`) are recorded as bare strings and
+/// omitted entirely when absent, so structured logs never show `Some(..)`/`None`.
 #[track_caller]
-fn display_with_level<T: DisplayMetadata + ?Sized>(level: PrettyMessageLevel, data: &T) {
+fn display_with_level<T: DisplayMetadata + ?Sized>(
+	level: PrettyMessageLevel,
+	data: &T,
+	command: Option<&str>,
+) {
 	let title = data.title();
 	let code = data.code();
 	let message = data.message();
 	let context = data.context();
 	let hint = data.hint();
+	let causes = data.causes();
 
 	let location = std::panic::Location::caller();
 	let location_str = format!("{}:{}", location.file(), location.line());
 
+	if matches!(level, PrettyMessageLevel::Error | PrettyMessageLevel::Warning)
+		&& is_github_actions(std::env::var("GITHUB_ACTIONS").ok().as_deref())
+	{
+		write_annotation(&github_actions_annotation(level, data, location.file(), location.line()));
+	}
+
 	match level {
 		PrettyMessageLevel::Error => tracing::error!(
 			message_code = code,
 			message_type = title,
 			message = %message,
-			context = ?context,
-			hint = ?hint,
+			context = context.as_deref(),
+			hint = hint,
 			location = %location_str,
 			"Error displayed to user"
 		),
@@ -202,8 +246,9 @@ fn display_with_level<T: DisplayMetadata + ?Sized>(level: PrettyMessageLevel, da
 			message_code = code,
 			message_type = title,
 			message = %message,
-			context = ?context,
-			hint = ?hint,
+			context = context.as_deref(),
+			hint = hint,
+			command = command,
 			location = %location_str,
 			"Warning displayed to user"
 		),
@@ -211,8 +256,8 @@ fn display_with_level<T: DisplayMetadata + ?Sized>(level: PrettyMessageLevel, da
 			message_code = code,
 			message_type = title,
 			message = %message,
-			context = ?context,
-			hint = ?hint,
+			context = context.as_deref(),
+			hint = hint,
 			location = %location_str,
 			"Info message displayed to user"
 		),
@@ -220,8 +265,8 @@ fn display_with_level<T: DisplayMetadata + ?Sized>(level: PrettyMessageLevel, da
 			message_code = code,
 			message_type = title,
 			message = %message,
-			context = ?context,
-			hint = ?hint,
+			context = context.as_deref(),
+			hint = hint,
 			location = %location_str,
 			"Success message displayed to user"
 		),
@@ -229,21 +274,26 @@ fn display_with_level<T: DisplayMetadata + ?Sized>(level: PrettyMessageLevel, da
 			message_code = code,
 			message_type = title,
 			message = %message,
-			context = ?context,
-			hint = ?hint,
+			context = context.as_deref(),
+			hint = hint,
 			location = %location_str,
 			"Input message displayed to user"
 		),
 	}
 
-	print_pretty_message(
+	print_message_parts(
 		level,
-		title,
-		code,
-		&message,
-		context.as_deref(),
-		hint,
-		Some(&location_str),
+		&MessageParts {
+			title,
+			code,
+			message: &message,
+			context: context.as_deref(),
+			hint,
+			location: Some(&location_str),
+			command,
+			causes: &causes,
+			..MessageParts::default()
+		},
 	);
 }
 
@@ -252,25 +302,188 @@ fn display_with_level<T: DisplayMetadata + ?Sized>(level: PrettyMessageLevel, da
 /// This is the generic replacement for the previous `GmnError`-only API.
 #[track_caller]
 pub fn display_error<T: DisplayMetadata + ?Sized>(data: &T) {
-	display_with_level(PrettyMessageLevel::Error, data);
+	display_with_level(PrettyMessageLevel::Error, data, None);
 }
 
 /// Display a warning from any metadata provider.
 #[track_caller]
 pub fn display_warning<T: DisplayMetadata + ?Sized>(data: &T) {
-	display_with_level(PrettyMessageLevel::Warning, data);
+	display_with_level(PrettyMessageLevel::Warning, data, None);
+}
+
+/// Display a warning together with a shell command that fixes it.
+///
+/// The command is rendered below the hint as `$ <command>` in a code color, so users
+/// can copy it straight into their shell.
+///
+/// Example:
+/// `display_warning_with_command(&msg, "export GMN_LOG_LEVEL=debug");`
+#[track_caller]
+pub fn display_warning_with_command<T: DisplayMetadata + ?Sized>(data: &T, command: &str) {
+	display_with_level(PrettyMessageLevel::Warning, data, Some(command));
 }
 
 /// Display an info message from any metadata provider.
 #[track_caller]
 pub fn display_info<T: DisplayMetadata + ?Sized>(data: &T) {
-	display_with_level(PrettyMessageLevel::Info, data);
+	display_with_level(PrettyMessageLevel::Info, data, None);
 }
 
 /// Display a success message from any metadata provider.
 #[track_caller]
 pub fn display_success<T: DisplayMetadata + ?Sized>(data: &T) {
-	display_with_level(PrettyMessageLevel::Success, data);
+	display_with_level(PrettyMessageLevel::Success, data, None);
+}
+
+/// Code shown on the success frame printed by [`report_operation`].
+pub const OPERATION_SUCCESS_CODE: &str = "GMN-OP-000";
+
+/// Display the outcome of a finished operation.
+///
+/// On `Ok`, prints a success frame titled `name` reading "`name` completed in `N`ms",
+/// unless `quiet` is set. On `Err`, always displays the error. This is the function
+/// behind [`operation_reported!`](crate::operation_reported); call it directly when
+/// the timing is measured elsewhere.
+#[track_caller]
+pub fn report_operation<T, E: DisplayMetadata>(
+	name: &str,
+	elapsed: std::time::Duration,
+	result: &Result<T, E>,
+	quiet: bool,
+) {
+	match result {
+		Ok(_) if quiet => {}
+		Ok(_) => {
+			let message = format!("{name} completed in {}ms", elapsed.as_millis());
+			display_success(&DisplayMessage {
+				title: name,
+				code: OPERATION_SUCCESS_CODE,
+				message: &message,
+				context: None,
+				hint: None,
+			});
+		}
+		Err(err) => display_error(err),
+	}
+}
+
+/// Code shown on the info frame printed by [`display_config_change`].
+pub const CONFIG_CHANGE_CODE: &str = "GMN-CFG-000";
+
+/// Confirm a runtime configuration change with an info frame.
+///
+/// The frame reads `field: old → new`, with the old value dimmed and the new one
+/// highlighted, so operators can see at a glance what a reconfiguration did.
+///
+/// Example:
+/// `display_config_change("log_level", "info", "debug");`
+#[track_caller]
+pub fn display_config_change(field: &str, old: &str, new: &str) {
+	let message = config_change_line(field, old, new);
+	display_info(&DisplayMessage {
+		title: "Configuration Changed",
+		code: CONFIG_CHANGE_CODE,
+		message: &message,
+		context: None,
+		hint: None,
+	});
+}
+
+fn config_change_line(field: &str, old: &str, new: &str) -> String {
+	format!("{field}: {} → {}", old.dimmed(), new.bold().green())
+}
+
+/// Emit an error as a GitHub Actions `::error` workflow command.
+///
+/// The annotation points at the caller's file and line, and carries the code and
+/// title as its heading and the message (plus hint, when present) as its body. It is
+/// written to stderr, where the Actions runner also picks up workflow commands, so the
+/// program's stdout stays clean for piping.
+///
+/// [`display_error`] already emits this automatically when `GITHUB_ACTIONS=true`; call
+/// this directly to annotate without drawing the pretty frame.
+#[track_caller]
+pub fn display_error_github_actions<T: DisplayMetadata + ?Sized>(data: &T) {
+	let location = std::panic::Location::caller();
+	write_annotation(&github_actions_annotation(
+		PrettyMessageLevel::Error,
+		data,
+		location.file(),
+		location.line(),
+	));
+}
+
+/// Write a workflow command line to stderr, ignoring failures like the pretty frames do
+fn write_annotation(annotation: &str) {
+	let _ = writeln!(std::io::stderr().lock(), "{annotation}");
+}
+
+/// Format a GitHub Actions workflow command annotating `file:line` with `data`.
+///
+/// Errors map to `::error`, warnings to `::warning`, and every other level to
+/// `::notice`. Property and message values are escaped per the workflow command
+/// syntax, so multi-line messages and titles containing `:` or `,` stay intact.
+///
+/// ## Example
+///
+/// ```rust
+/// use gmn_core::error_display::{DisplayMessage, github_actions_annotation};
+/// use gmn_core::print_pretty_error::PrettyMessageLevel;
+///
+/// let msg = DisplayMessage {
+///     title: "Build Failed",
+///     code: "BUILD-001",
+///     message: "Missing artifact",
+///     context: None,
+///     hint: None,
+/// };
+///
+/// assert_eq!(
+///     github_actions_annotation(PrettyMessageLevel::Error, &msg, "src/main.rs", 7),
+///     "::error file=src/main.rs,line=7,title=BUILD-001 Build Failed::Missing artifact",
+/// );
+/// ```
+pub fn github_actions_annotation<T: DisplayMetadata + ?Sized>(
+	level: PrettyMessageLevel,
+	data: &T,
+	file: &str,
+	line: u32,
+) -> String {
+	let command = match level {
+		PrettyMessageLevel::Error => "error",
+		PrettyMessageLevel::Warning => "warning",
+		PrettyMessageLevel::Info | PrettyMessageLevel::Success | PrettyMessageLevel::Input => {
+			"notice"
+		}
+	};
+
+	let mut body = data.message();
+	if let Some(hint) = data.hint() {
+		body.push_str("\nHint: ");
+		body.push_str(hint);
+	}
+
+	format!(
+		"::{command} file={},line={line},title={}::{}",
+		escape_workflow_property(file),
+		escape_workflow_property(&format!("{} {}", data.code(), data.title())),
+		escape_workflow_data(&body)
+	)
+}
+
+/// Whether the `GITHUB_ACTIONS` environment value indicates a GitHub Actions runner.
+fn is_github_actions(value: Option<&str>) -> bool {
+	value.is_some_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
+/// Escape a workflow command message body.
+fn escape_workflow_data(value: &str) -> String {
+	value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value (`file=`, `title=`, ...).
+fn escape_workflow_property(value: &str) -> String {
+	escape_workflow_data(value).replace(':', "%3A").replace(',', "%2C")
 }
 
 /// Display a generic `std::error::Error` without requiring `GmnError`.
@@ -301,3 +514,287 @@ pub fn display_std_error<E: std::error::Error + ?Sized>(
 
 	display_error(&msg);
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::errors::ConfigError;
+	use std::collections::BTreeMap;
+	use std::sync::{Arc, Mutex, PoisonError};
+	use tracing::field::{Field, Visit};
+	use tracing_subscriber::layer::{Context, SubscriberExt};
+
+	type Fields = BTreeMap<String, String>;
+
+	struct FieldVisitor<'a>(&'a mut Fields);
+
+	impl Visit for FieldVisitor<'_> {
+		fn record_str(&mut self, field: &Field, value: &str) {
+			self.0.insert(field.name().to_string(), value.to_string());
+		}
+
+		fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+			self.0.insert(field.name().to_string(), format!("{value:?}"));
+		}
+	}
+
+	/// Layer capturing the fields of every event
+	#[derive(Clone, Default)]
+	struct FieldCapture {
+		events: Arc<Mutex<Vec<Fields>>>,
+	}
+
+	impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for FieldCapture {
+		fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+			let mut fields = Fields::new();
+			event.record(&mut FieldVisitor(&mut fields));
+			if let Ok(mut events) = self.events.lock() {
+				events.push(fields);
+			}
+		}
+	}
+
+	fn captured_events(f: impl FnOnce()) -> Vec<Fields> {
+		let capture = FieldCapture::default();
+		let subscriber = tracing_subscriber::registry().with(capture.clone());
+		tracing::subscriber::with_default(subscriber, f);
+		capture.events.lock().map(|events| events.clone()).unwrap_or_default()
+	}
+
+	fn captured_fields(message: &DisplayMessage<'_>) -> Vec<Fields> {
+		captured_events(|| display_error(message))
+	}
+
+	#[test]
+	fn test_operation_reported_shows_success_frame_on_ok() {
+		let mut result = None;
+		let events = captured_events(|| {
+			result = Some(crate::operation_reported!("load config", { Ok::<_, GmnError>(42) }));
+		});
+
+		assert!(matches!(result, Some(Ok(42))));
+		assert_eq!(events.len(), 1);
+		assert_eq!(events[0].get("message_code").map(String::as_str), Some(OPERATION_SUCCESS_CODE));
+		let message = events[0].get("message").cloned().unwrap_or_default();
+		assert!(message.starts_with("load config completed in "), "{message}");
+		assert!(message.ends_with("ms"), "{message}");
+	}
+
+	#[test]
+	fn test_operation_reported_shows_error_frame_on_err() {
+		let mut result = None;
+		let events = captured_events(|| {
+			result = Some(crate::operation_reported!("load config", quiet = true, {
+				Err::<(), _>(GmnError::from(ConfigError::InvalidLogLevel {
+					level: "loud".to_string(),
+				}))
+			}));
+		});
+
+		assert!(matches!(result, Some(Err(GmnError::Config(_)))));
+		assert_eq!(events.len(), 1);
+		assert_eq!(events[0].get("message_code").map(String::as_str), Some("GMN-CFG-001"));
+	}
+
+	#[test]
+	fn test_operation_reported_quiet_skips_success_frame() {
+		let events = captured_events(|| {
+			let _ = crate::operation_reported!("warmup", quiet = true, { Ok::<_, GmnError>(()) });
+		});
+
+		assert!(events.is_empty());
+	}
+
+	/// Serializes the tests that install a sink
+	static SINK: Mutex<()> = Mutex::new(());
+
+	#[test]
+	fn test_sink_receives_rendered_messages() {
+		let _sink = SINK.lock().unwrap_or_else(PoisonError::into_inner);
+		let captured: Arc<Mutex<Vec<(String, String)>>> = Arc::default();
+		let sink_store = Arc::clone(&captured);
+		set_sink(Arc::new(move |level, rendered| {
+			if let Ok(mut messages) = sink_store.lock() {
+				messages.push((format!("{level:?}"), rendered.to_string()));
+			}
+		}));
+
+		display_error(&DisplayMessage {
+			title: "Sink Test",
+			code: "SNK-001",
+			message: "Routed to the sink",
+			context: None,
+			hint: None,
+		});
+		clear_sink();
+
+		let messages = captured.lock().map(|messages| messages.clone()).unwrap_or_default();
+		// Other tests may print while the sink is installed; find this one's frame
+		let frame = messages.iter().find(|(_, rendered)| rendered.contains("SNK-001"));
+		assert!(matches!(frame, Some((level, rendered))
+			if level == "Error" && rendered.contains("Routed to the sink")));
+	}
+
+	#[test]
+	fn test_sink_can_print_and_clear_itself() {
+		let _sink = SINK.lock().unwrap_or_else(PoisonError::into_inner);
+		let captured: Arc<Mutex<Vec<String>>> = Arc::default();
+		let sink_store = Arc::clone(&captured);
+		set_sink(Arc::new(move |_, rendered| {
+			if rendered.contains("SNK-002") {
+				display_info(&DisplayMessage {
+					title: "Nested",
+					code: "SNK-003",
+					message: "Printed from the sink",
+					context: None,
+					hint: None,
+				});
+				clear_sink();
+			}
+			if let Ok(mut messages) = sink_store.lock() {
+				messages.push(rendered.to_string());
+			}
+		}));
+
+		display_error(&DisplayMessage {
+			title: "Reentrant Sink",
+			code: "SNK-002",
+			message: "Reaches a sink that prints and clears itself",
+			context: None,
+			hint: None,
+		});
+
+		let messages = captured.lock().map(|messages| messages.clone()).unwrap_or_default();
+		assert!(messages.iter().any(|rendered| rendered.contains("SNK-002")), "{messages:?}");
+		assert!(messages.iter().any(|rendered| rendered.contains("SNK-003")), "{messages:?}");
+	}
+
+	#[test]
+	fn test_causes_skip_wrapped_domain_error() {
+		let err = GmnError::from(ConfigError::InvalidOutputPath {
+			path: "/nope/app.log".into(),
+			source: std::io::Error::new(std::io::ErrorKind::NotFound, "no such directory"),
+		});
+
+		assert_eq!(DisplayMetadata::causes(&err), vec!["no such directory".to_string()]);
+
+		let io_err = std::io::Error::other(err);
+		let generic = GenericErrorDisplay::new(&io_err);
+		assert_eq!(generic.causes().last().map(String::as_str), Some("no such directory"));
+	}
+
+	#[test]
+	fn test_display_event_records_bare_optional_fields() {
+		let events = captured_fields(&DisplayMessage {
+			title: "Load Failed",
+			code: "LD-001",
+			message: "Could not load",
+			context: Some("path=/etc/app.toml"),
+			hint: Some("Check the path"),
+		});
+
+		assert_eq!(events.len(), 1);
+		assert_eq!(events[0].get("context").map(String::as_str), Some("path=/etc/app.toml"));
+		assert_eq!(events[0].get("hint").map(String::as_str), Some("Check the path"));
+	}
+
+	#[test]
+	fn test_display_event_omits_absent_optional_fields() {
+		let events = captured_fields(&DisplayMessage {
+			title: "Load Failed",
+			code: "LD-001",
+			message: "Could not load",
+			context: None,
+			hint: None,
+		});
+
+		assert_eq!(events.len(), 1);
+		assert!(!events[0].contains_key("context"));
+		assert!(!events[0].contains_key("hint"));
+		assert_eq!(events[0].get("message_code").map(String::as_str), Some("LD-001"));
+	}
+
+	#[test]
+	fn test_github_actions_annotation_format() {
+		let error = GmnError::from(ConfigError::InvalidLogLevel { level: "loud".to_string() });
+
+		let annotation =
+			github_actions_annotation(PrettyMessageLevel::Error, &error, "src/main.rs", 42);
+
+		assert!(annotation.starts_with(
+			"::error file=src/main.rs,line=42,title=GMN-CFG-001 Configuration Error::"
+		));
+		assert!(annotation.contains("::Configuration error: Invalid log level: loud%0AHint: "));
+		assert_eq!(annotation.lines().count(), 1);
+	}
+
+	#[test]
+	fn test_github_actions_escaping_and_levels() {
+		let msg = DisplayMessage {
+			title: "Disk: low, very",
+			code: "DSK-1",
+			message: "100% used\nsecond line",
+			context: None,
+			hint: None,
+		};
+
+		assert_eq!(
+			github_actions_annotation(PrettyMessageLevel::Warning, &msg, "a,b.rs", 3),
+			"::warning file=a%2Cb.rs,line=3,title=DSK-1 Disk%3A low%2C very::100%25 used%0Asecond line"
+		);
+		assert!(
+			github_actions_annotation(PrettyMessageLevel::Info, &msg, "x.rs", 1)
+				.starts_with("::notice ")
+		);
+	}
+
+	#[test]
+	fn test_github_actions_annotation_goes_to_stderr() -> std::io::Result<()> {
+		let output = std::process::Command::new(std::env::current_exe()?)
+			.args(["--exact", "--include-ignored", "--nocapture"])
+			.arg("error_display::tests::display_error_under_github_actions")
+			.env("GITHUB_ACTIONS", "true")
+			.output()?;
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		let stderr = String::from_utf8_lossy(&output.stderr);
+
+		assert!(output.status.success(), "{stdout}{stderr}");
+		assert!(stdout.contains("1 passed"), "{stdout}");
+		let annotation = stderr.lines().find(|line| line.starts_with("::error "));
+		assert!(annotation.is_some_and(|line| line.contains("GHA-001")), "{stderr}");
+		assert!(!stdout.contains("::error"), "{stdout}");
+		Ok(())
+	}
+
+	#[test]
+	#[ignore = "run with GITHUB_ACTIONS=true by test_github_actions_annotation_goes_to_stderr"]
+	fn display_error_under_github_actions() {
+		display_error(&DisplayMessage {
+			title: "Annotated",
+			code: "GHA-001",
+			message: "Shown on the pull request",
+			context: None,
+			hint: None,
+		});
+	}
+
+	#[test]
+	fn test_github_actions_detection() {
+		assert!(is_github_actions(Some("true")));
+		assert!(is_github_actions(Some("TRUE")));
+		assert!(!is_github_actions(Some("false")));
+		assert!(!is_github_actions(None));
+	}
+
+	#[test]
+	fn test_config_change_shows_both_values_and_arrow() {
+		let events = captured_events(|| display_config_change("log_level", "info", "debug"));
+
+		assert_eq!(events.len(), 1);
+		assert_eq!(events[0].get("message_code").map(String::as_str), Some(CONFIG_CHANGE_CODE));
+		let message = events[0].get("message").cloned().unwrap_or_default();
+		assert!(message.starts_with("log_level: "), "{message}");
+		let (old, new) = message.split_once(" → ").unwrap_or_default();
+		assert!(old.contains("info") && new.contains("debug"), "{message}");
+	}
+}
@@ -3,7 +3,8 @@
 //! This module defines a comprehensive error hierarchy using `thiserror` for
 //! type-safe error handling throughout the gmn-core ecosystem.
 
-use std::fmt;
+use crate::error_display::DisplayMetadata;
+use crate::print_pretty_error::truncate_chars;
 
 /// Shared metadata contract for domain errors in `gmn_core`.
 ///
@@ -27,6 +28,9 @@ pub trait ErrorMetadata {
 /// Result type alias for gmn-core operations
 pub type Result<T> = std::result::Result<T, GmnError>;
 
+/// Boxed underlying error (driver, HTTP client, ...) kept as the `source` of a domain error.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
 /// Main error type for the GabMacN core library
 #[derive(Debug, thiserror::Error)]
 pub enum GmnError {
@@ -62,6 +66,10 @@ pub enum GmnError {
 	#[error("API error: {0}")]
 	Api(#[from] ApiError),
 
+	/// I/O errors propagated with `?` from filesystem, socket and pipe operations
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+
 	/// Generic internal error
 	#[error("Internal error: {0}")]
 	Internal(String),
@@ -92,6 +100,151 @@ impl GmnError {
 		}
 	}
 
+	/// Flatten this error and its causes into `(code, message)` pairs, outermost first.
+	///
+	/// The first pair describes `self`. The domain error wrapped by a variant (e.g. the
+	/// [`ConfigError`] inside [`GmnError::Config`]) shares that entry rather than
+	/// repeating it; the walk continues with whatever *it* was caused by. Causes that
+	/// are gmn errors report their own code, while foreign errors (`std::io::Error`,
+	/// driver errors, ...) use [`FOREIGN_ERROR_CODE`].
+	///
+	/// ```
+	/// use gmn_core::errors::{ConfigError, GmnError};
+	///
+	/// let err = GmnError::from(ConfigError::EnvVarParse {
+	///     var: "GMN_LOG_ANSI".into(),
+	///     value: "maybe".into(),
+	/// });
+	/// assert_eq!(err.flatten()[0].0, "GMN-CFG-004");
+	/// ```
+	pub fn flatten(&self) -> Vec<(String, String)> {
+		let mut pairs = vec![(self.code().to_string(), self.to_string())];
+		let mut cause = skip_wrapped_payload(self);
+
+		while let Some(err) = cause {
+			if let Some(gmn) = err.downcast_ref::<Self>() {
+				pairs.push((gmn.code().to_string(), gmn.to_string()));
+				cause = skip_wrapped_payload(gmn);
+				continue;
+			}
+
+			let code = known_metadata(err).map_or(FOREIGN_ERROR_CODE, ErrorMetadata::code);
+			pairs.push((code.to_string(), err.to_string()));
+			cause = err.source();
+		}
+
+		pairs
+	}
+
+	/// Whether the failed operation may succeed if tried again unchanged.
+	///
+	/// The classification is part of the public contract and only grows:
+	///
+	/// - retryable: [`ApiError::ServerError`] with a 5xx status, [`ApiError::NetworkError`],
+	///   [`ApiError::Timeout`], every [`GmnError::RateLimit`], and
+	///   [`DatabaseError::ConnectionFailed`], [`DatabaseError::TransactionFailed`],
+	///   [`DatabaseError::PoolTimeout`] and [`DatabaseError::Deadlock`]; among the generic
+	///   errors, [`GenericError::Timeout`] and [`GenericError::ServiceUnavailable`]; and
+	///   [`GmnError::Io`] of kind `Interrupted`, `WouldBlock`, `TimedOut`,
+	///   `ConnectionReset` or `ConnectionAborted`
+	/// - not retryable: everything else, notably configuration, auth, validation and 4xx
+	///   server errors, which fail the same way until something changes
+	///
+	/// Use [`retry_after`](Self::retry_after) to decide how long to wait.
+	///
+	/// ```
+	/// use gmn_core::errors::{ApiError, GmnError};
+	///
+	/// let err = GmnError::from(ApiError::ServerError { status: 503, message: "busy".into() });
+	/// assert!(err.is_retryable());
+	/// ```
+	pub fn is_retryable(&self) -> bool {
+		match self {
+			Self::Api(api) => match api {
+				ApiError::ServerError { status, .. } => *status >= 500,
+				ApiError::NetworkError { .. } | ApiError::Timeout { .. } => true,
+				ApiError::InvalidResponse(_) => false,
+			},
+			Self::RateLimit(_) => true,
+			Self::Database(db) => matches!(
+				db,
+				DatabaseError::ConnectionFailed { .. }
+					| DatabaseError::TransactionFailed(_)
+					| DatabaseError::PoolTimeout { .. }
+					| DatabaseError::Deadlock { .. }
+			),
+			Self::Generic(generic) => matches!(
+				generic,
+				GenericError::Timeout { .. } | GenericError::ServiceUnavailable { .. }
+			),
+			Self::Io(io) => matches!(
+				io.kind(),
+				std::io::ErrorKind::Interrupted
+					| std::io::ErrorKind::WouldBlock
+					| std::io::ErrorKind::TimedOut
+					| std::io::ErrorKind::ConnectionReset
+					| std::io::ErrorKind::ConnectionAborted
+			),
+			Self::Config(_)
+			| Self::Tracing(_)
+			| Self::CLI(_)
+			| Self::Auth(_)
+			| Self::Internal(_) => false,
+		}
+	}
+
+	/// How long to wait before retrying, when the error says so.
+	///
+	/// For a [`GmnError::RateLimit`] this is its `retry_after`, else the time left until
+	/// its `reset_at`, else the whole rate-limit window. Otherwise `None`, leaving the
+	/// delay to the caller's backoff policy.
+	pub fn retry_after(&self) -> Option<std::time::Duration> {
+		let Self::RateLimit(rate_limit) = self else { return None };
+
+		let until_reset = rate_limit.reset_at.map(|reset_at| {
+			reset_at.duration_since(std::time::SystemTime::now()).unwrap_or_default()
+		});
+		Some(
+			rate_limit
+				.retry_after
+				.or(until_reset)
+				.unwrap_or_else(|| std::time::Duration::from_secs(rate_limit.window_secs)),
+		)
+	}
+
+	/// Serialize this error as a JSON object for API responses and structured sinks.
+	///
+	/// Carries the same fields the pretty frame shows: `code`, `title`, `message`,
+	/// `context` and `hint` (`null` when absent), plus `causes`, the messages of the
+	/// [source chain](Self::flatten) outermost first.
+	///
+	/// ```
+	/// use gmn_core::errors::{ConfigError, GmnError};
+	///
+	/// let err = GmnError::from(ConfigError::InvalidLogLevel { level: "loud".into() });
+	/// let json = err.to_json();
+	/// assert_eq!(json["code"], "GMN-CFG-001");
+	/// assert_eq!(json["title"], "Configuration Error");
+	/// ```
+	pub fn to_json(&self) -> serde_json::Value {
+		let causes: Vec<String> =
+			self.flatten().into_iter().skip(1).map(|(_, message)| message).collect();
+
+		serde_json::json!({
+			"code": self.code(),
+			"title": DisplayMetadata::title(self),
+			"message": self.to_string(),
+			"context": self.context(),
+			"hint": self.hint(),
+			"causes": causes,
+		})
+	}
+
+	/// [`to_json`](Self::to_json) rendered as a compact single-line string for logs.
+	pub fn to_json_string(&self) -> String {
+		self.to_json().to_string()
+	}
+
 	fn metadata(&self) -> &dyn ErrorMetadata {
 		match self {
 			Self::Generic(e) => e,
@@ -102,11 +255,50 @@ impl GmnError {
 			Self::Auth(e) => e,
 			Self::RateLimit(e) => e,
 			Self::Api(e) => e,
+			Self::Io(e) => e,
 			Self::Internal(_) => unreachable!("internal is handled separately"),
 		}
 	}
 }
 
+/// Code reported by [`GmnError::flatten`] for causes that are not gmn errors.
+pub const FOREIGN_ERROR_CODE: &str = "GMN-EXT-000";
+
+/// First cause of `err` beyond the domain error its variant wraps.
+fn skip_wrapped_payload(err: &GmnError) -> Option<&(dyn std::error::Error + 'static)> {
+	use std::error::Error as _;
+
+	match err {
+		GmnError::Internal(_) => None,
+		wrapper => wrapper.source().and_then(std::error::Error::source),
+	}
+}
+
+/// Metadata for `err` when it is one of this crate's domain error types.
+fn known_metadata<'a>(err: &'a (dyn std::error::Error + 'static)) -> Option<&'a dyn ErrorMetadata> {
+	macro_rules! try_downcast {
+		($($ty:ty),*) => {
+			$(
+				if let Some(e) = err.downcast_ref::<$ty>() {
+					return Some(e);
+				}
+			)*
+		};
+	}
+
+	try_downcast!(
+		GenericError,
+		ConfigError,
+		TracingError,
+		CLIError,
+		DatabaseError,
+		AuthError,
+		RateLimitError,
+		ApiError
+	);
+	None
+}
+
 // ============================================================================
 // Generic Errors
 // ============================================================================
@@ -196,6 +388,22 @@ pub enum GenericError {
 		message: Option<String>,
 	},
 
+	/// Operation failed inside a tracing span; carries the span's fields as context.
+	///
+	/// Usually built with
+	/// [`error_with_span_context`](crate::tracing::instrumentation::error_with_span_context).
+	#[error("{message}")]
+	InSpan {
+		/// Error code chosen by the caller, e.g. `GMN-DB-002` for a failed query.
+		code: &'static str,
+		/// Name of the span the failure happened in.
+		span: String,
+		/// Fields recorded on the span, in recording order.
+		fields: Vec<(String, String)>,
+		/// Human-readable message.
+		message: String,
+	},
+
 	/// Catch-all generic error.
 	#[error("{message}")]
 	Other {
@@ -218,6 +426,7 @@ impl ErrorMetadata for GenericError {
 			Self::Unsupported { .. } => "GMN-GEN-009",
 			Self::ServiceUnavailable { .. } => "GMN-GEN-010",
 			Self::Other { .. } => "GMN-GEN-011",
+			Self::InSpan { code, .. } => code,
 		}
 	}
 
@@ -251,7 +460,7 @@ impl ErrorMetadata for GenericError {
 			Self::ServiceUnavailable { .. } => {
 				Some("Retry later; dependency may be degraded or under maintenance")
 			}
-			Self::Other { .. } => None,
+			Self::InSpan { .. } | Self::Other { .. } => None,
 		}
 	}
 
@@ -301,6 +510,12 @@ impl ErrorMetadata for GenericError {
 				}
 				Some(ctx)
 			}
+			Self::InSpan { span, fields, .. } => Some(
+				std::iter::once(format!("span={span}"))
+					.chain(fields.iter().map(|(key, value)| format!("{key}={value}")))
+					.collect::<Vec<_>>()
+					.join(", "),
+			),
 			Self::Other { message } => Some(format!("details={}", message)),
 		}
 	}
@@ -345,6 +560,41 @@ pub enum ConfigError {
 		/// The value that failed to parse
 		value: String,
 	},
+
+	/// Configuration file could not be read
+	#[error("Failed to read config file: {path}")]
+	FileRead {
+		/// The config file path
+		path: String,
+		/// The underlying error
+		#[source]
+		source: std::io::Error,
+	},
+
+	/// Configuration file is not valid TOML/JSON or does not match the expected fields
+	#[error("Malformed config file {path}: {message}")]
+	FileParse {
+		/// The config file path
+		path: String,
+		/// What the parser rejected
+		message: String,
+	},
+
+	/// Configuration file has an extension no parser handles
+	#[error("Unsupported config file type: {path}")]
+	UnsupportedFileType {
+		/// The config file path
+		path: String,
+	},
+
+	/// Timestamp format description does not parse
+	#[error("Invalid time format {format:?}: {message}")]
+	InvalidTimeFormat {
+		/// The format description
+		format: String,
+		/// What the parser rejected
+		message: String,
+	},
 }
 
 impl ErrorMetadata for ConfigError {
@@ -354,6 +604,10 @@ impl ErrorMetadata for ConfigError {
 			Self::InvalidLogFormat { .. } => "GMN-CFG-002",
 			Self::InvalidOutputPath { .. } => "GMN-CFG-003",
 			Self::EnvVarParse { .. } => "GMN-CFG-004",
+			Self::FileRead { .. } => "GMN-CFG-005",
+			Self::FileParse { .. } => "GMN-CFG-006",
+			Self::UnsupportedFileType { .. } => "GMN-CFG-007",
+			Self::InvalidTimeFormat { .. } => "GMN-CFG-008",
 		}
 	}
 
@@ -369,6 +623,14 @@ impl ErrorMetadata for ConfigError {
 			Self::EnvVarParse { .. } => {
 				Some("Check the environment variable value matches the expected format")
 			}
+			Self::FileRead { .. } => Some("Check the config file exists and is readable"),
+			Self::FileParse { .. } => Some(
+				"Check the field names and values, e.g. format = \"json\" and output = \"stderr\" or { file = \"app.log\" }",
+			),
+			Self::UnsupportedFileType { .. } => Some("Use a .toml or .json config file"),
+			Self::InvalidTimeFormat { .. } => {
+				Some("Use time format description syntax, e.g. [hour]:[minute]:[second]")
+			}
 		}
 	}
 
@@ -413,6 +675,44 @@ pub enum TracingError {
 		#[source]
 		source: tracing::subscriber::SetGlobalDefaultError,
 	},
+
+	/// Failed to install the `log` crate bridge
+	#[cfg(feature = "log-compat")]
+	#[error("Failed to forward log records into tracing")]
+	LogBridgeFailed {
+		/// The underlying error
+		#[source]
+		source: tracing_log::log::SetLoggerError,
+	},
+
+	/// Failed to swap the level filter of the subscriber
+	#[error("Failed to reload the tracing level filter")]
+	ReloadFailed {
+		/// The underlying error
+		#[source]
+		source: tracing_subscriber::reload::Error,
+	},
+
+	/// The configured output cannot be used
+	#[error("Log output {output} is unavailable")]
+	OutputUnavailable {
+		/// The output (`journald` or `syslog`)
+		output: String,
+		/// Why it is unavailable
+		#[source]
+		source: BoxError,
+	},
+
+	/// Failed to set up or flush the OpenTelemetry exporter
+	#[cfg(feature = "otel")]
+	#[error("Failed to export spans to OTLP endpoint {endpoint}")]
+	OtlpFailed {
+		/// The configured collector endpoint
+		endpoint: String,
+		/// The underlying error
+		#[source]
+		source: BoxError,
+	},
 }
 
 impl ErrorMetadata for TracingError {
@@ -421,6 +721,12 @@ impl ErrorMetadata for TracingError {
 			Self::AlreadyInitialized => "GMN-TRC-001",
 			Self::FileCreationFailed { .. } => "GMN-TRC-002",
 			Self::SetGlobalFailed { .. } => "GMN-TRC-003",
+			#[cfg(feature = "log-compat")]
+			Self::LogBridgeFailed { .. } => "GMN-TRC-004",
+			#[cfg(feature = "otel")]
+			Self::OtlpFailed { .. } => "GMN-TRC-005",
+			Self::ReloadFailed { .. } => "GMN-TRC-006",
+			Self::OutputUnavailable { .. } => "GMN-TRC-007",
 		}
 	}
 
@@ -435,12 +741,28 @@ impl ErrorMetadata for TracingError {
 			Self::SetGlobalFailed { .. } => {
 				Some("This usually indicates tracing was already initialized elsewhere")
 			}
+			#[cfg(feature = "log-compat")]
+			Self::LogBridgeFailed { .. } => Some(
+				"Another `log` logger is already installed. Disable capture_log or remove the other logger.",
+			),
+			Self::ReloadFailed { .. } => {
+				Some("The subscriber was dropped; reload through the guard of an installed one")
+			}
+			Self::OutputUnavailable { .. } => {
+				Some("Enable the matching gmn_core feature and check the system logger is running")
+			}
+			#[cfg(feature = "otel")]
+			Self::OtlpFailed { .. } => Some(
+				"Check the endpoint is a full OTLP/HTTP URL such as http://localhost:4318/v1/traces",
+			),
 		}
 	}
 
 	fn context(&self) -> Option<String> {
 		match self {
 			Self::FileCreationFailed { path, .. } => Some(format!("Log file path: {}", path)),
+			#[cfg(feature = "otel")]
+			Self::OtlpFailed { endpoint, .. } => Some(format!("Endpoint: {endpoint}")),
 			_ => None,
 		}
 	}
@@ -502,44 +824,86 @@ impl ErrorMetadata for CLIError {
 // ============================================================================
 
 /// Database-related errors (placeholder for future implementation)
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum DatabaseError {
 	/// Connection failed
-	ConnectionFailed(String),
+	#[error("Database connection failed: {message}")]
+	ConnectionFailed {
+		/// What went wrong, e.g. the address that could not be reached
+		message: String,
+		/// The driver error, when there is one
+		#[source]
+		source: Option<BoxError>,
+	},
 	/// Query failed
+	#[error("Database query failed: {0}")]
 	QueryFailed(String),
 	/// Transaction failed
+	#[error("Database transaction failed: {0}")]
 	TransactionFailed(String),
+	/// No pooled connection became available in time
+	#[error("Timed out after {waited_ms}ms waiting for a database connection")]
+	PoolTimeout {
+		/// How long the caller waited for a connection, in milliseconds
+		waited_ms: u64,
+		/// Maximum number of connections in the pool
+		pool_size: u32,
+	},
+	/// A write was rejected by a database constraint
+	#[error("Database constraint violated: {constraint}")]
+	ConstraintViolation {
+		/// Name of the violated constraint
+		constraint: String,
+		/// Table the constraint belongs to
+		table: String,
+	},
+	/// The transaction was aborted to resolve a deadlock
+	#[error("Database deadlock detected")]
+	Deadlock {
+		/// Table on which the deadlock was detected, when the driver reports it
+		table: Option<String>,
+	},
 }
 
-impl fmt::Display for DatabaseError {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		match self {
-			Self::ConnectionFailed(msg) => write!(f, "Database connection failed: {}", msg),
-			Self::QueryFailed(msg) => write!(f, "Database query failed: {}", msg),
-			Self::TransactionFailed(msg) => write!(f, "Database transaction failed: {}", msg),
-		}
-	}
-}
-
-impl std::error::Error for DatabaseError {}
-
 impl ErrorMetadata for DatabaseError {
 	fn code(&self) -> &'static str {
 		match self {
-			Self::ConnectionFailed(_) => "GMN-DB-001",
+			Self::ConnectionFailed { .. } => "GMN-DB-001",
 			Self::QueryFailed(_) => "GMN-DB-002",
 			Self::TransactionFailed(_) => "GMN-DB-003",
+			Self::PoolTimeout { .. } => "GMN-DB-004",
+			Self::ConstraintViolation { .. } => "GMN-DB-005",
+			Self::Deadlock { .. } => "GMN-DB-006",
 		}
 	}
 
 	fn hint(&self) -> Option<&str> {
 		match self {
-			Self::ConnectionFailed(_) => {
+			Self::ConnectionFailed { .. } => {
 				Some("Check database connection string and network connectivity")
 			}
 			Self::QueryFailed(_) => Some("Verify query syntax and database schema"),
 			Self::TransactionFailed(_) => Some("Check for conflicts or constraint violations"),
+			Self::PoolTimeout { .. } => {
+				Some("Increase the pool size or look for connections that are held too long")
+			}
+			Self::ConstraintViolation { .. } => {
+				Some("Check the submitted data against the table's unique and foreign keys")
+			}
+			Self::Deadlock { .. } => Some("Retry the transaction; deadlocks are usually transient"),
+		}
+	}
+
+	fn context(&self) -> Option<String> {
+		match self {
+			Self::PoolTimeout { waited_ms, pool_size } => {
+				Some(format!("Waited {waited_ms}ms on a pool of {pool_size} connections"))
+			}
+			Self::ConstraintViolation { constraint, table } => {
+				Some(format!("Constraint: {constraint} on table {table}"))
+			}
+			Self::Deadlock { table: Some(table) } => Some(format!("Table: {table}")),
+			_ => None,
 		}
 	}
 }
@@ -549,34 +913,41 @@ impl ErrorMetadata for DatabaseError {
 // ============================================================================
 
 /// Authentication/authorization errors (placeholder for future implementation)
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum AuthError {
 	/// Invalid credentials
+	#[error("Invalid credentials")]
 	InvalidCredentials,
 	/// Token expired
+	#[error("Authentication token expired")]
 	TokenExpired,
 	/// Insufficient permissions
+	#[error("Insufficient permissions")]
 	InsufficientPermissions,
+	/// Token could not be parsed or its signature did not verify
+	#[error("Invalid authentication token: {reason}")]
+	InvalidToken {
+		/// Why the token was rejected
+		reason: String,
+	},
+	/// Token is valid but lacks a scope the operation requires
+	#[error("Missing required scope: {required}")]
+	MissingScope {
+		/// Scope the operation requires
+		required: String,
+		/// Scopes the token actually carries
+		granted: Vec<String>,
+	},
 }
 
-impl fmt::Display for AuthError {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		match self {
-			Self::InvalidCredentials => write!(f, "Invalid credentials"),
-			Self::TokenExpired => write!(f, "Authentication token expired"),
-			Self::InsufficientPermissions => write!(f, "Insufficient permissions"),
-		}
-	}
-}
-
-impl std::error::Error for AuthError {}
-
 impl ErrorMetadata for AuthError {
 	fn code(&self) -> &'static str {
 		match self {
 			Self::InvalidCredentials => "GMN-AUTH-001",
 			Self::TokenExpired => "GMN-AUTH-002",
 			Self::InsufficientPermissions => "GMN-AUTH-003",
+			Self::InvalidToken { .. } => "GMN-AUTH-004",
+			Self::MissingScope { .. } => "GMN-AUTH-005",
 		}
 	}
 
@@ -585,6 +956,20 @@ impl ErrorMetadata for AuthError {
 			Self::InvalidCredentials => Some("Verify your API key or credentials"),
 			Self::TokenExpired => Some("Refresh your authentication token"),
 			Self::InsufficientPermissions => Some("Contact administrator for required permissions"),
+			Self::InvalidToken { .. } => Some("Sign in again to obtain a fresh token"),
+			Self::MissingScope { .. } => Some("Request a token that includes the required scope"),
+		}
+	}
+
+	fn context(&self) -> Option<String> {
+		match self {
+			Self::MissingScope { granted, .. } if granted.is_empty() => {
+				Some("Granted scopes: none".to_string())
+			}
+			Self::MissingScope { granted, .. } => {
+				Some(format!("Granted scopes: {}", granted.join(", ")))
+			}
+			_ => None,
 		}
 	}
 }
@@ -594,7 +979,15 @@ impl ErrorMetadata for AuthError {
 // ============================================================================
 
 /// Rate limiting errors (placeholder for future implementation)
-#[derive(Debug)]
+///
+/// When the limiter says when to come back (a `Retry-After` or `X-RateLimit-Reset`
+/// header), record it with [`with_retry_after`](Self::with_retry_after) and
+/// [`with_reset_at`](Self::with_reset_at).
+#[derive(Debug, thiserror::Error)]
+#[error(
+	"Rate limit exceeded: {requests} requests in {window_secs} seconds (limit: {limit}){}",
+	retry_after_suffix(*.retry_after)
+)]
 pub struct RateLimitError {
 	/// Number of requests made
 	pub requests: u32,
@@ -602,19 +995,41 @@ pub struct RateLimitError {
 	pub limit: u32,
 	/// Time window in seconds
 	pub window_secs: u64,
+	/// How long to wait before retrying, from `Retry-After`
+	pub retry_after: Option<std::time::Duration>,
+	/// When the window resets, from `X-RateLimit-Reset`
+	pub reset_at: Option<std::time::SystemTime>,
 }
 
-impl fmt::Display for RateLimitError {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"Rate limit exceeded: {} requests in {} seconds (limit: {})",
-			self.requests, self.window_secs, self.limit
-		)
+impl RateLimitError {
+	/// Create a rate-limit error without retry information.
+	pub const fn new(requests: u32, limit: u32, window_secs: u64) -> Self {
+		Self { requests, limit, window_secs, retry_after: None, reset_at: None }
+	}
+
+	/// Builder method to set how long to wait before retrying.
+	#[must_use]
+	pub const fn with_retry_after(mut self, retry_after: std::time::Duration) -> Self {
+		self.retry_after = Some(retry_after);
+		self
+	}
+
+	/// Builder method to set when the rate-limit window resets.
+	#[must_use]
+	pub const fn with_reset_at(mut self, reset_at: std::time::SystemTime) -> Self {
+		self.reset_at = Some(reset_at);
+		self
 	}
 }
 
-impl std::error::Error for RateLimitError {}
+/// Whole seconds in `duration`, rounded up so a wait is never understated.
+fn ceil_secs(duration: std::time::Duration) -> u64 {
+	duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+fn retry_after_suffix(retry_after: Option<std::time::Duration>) -> String {
+	retry_after.map(|wait| format!(", retry after {}s", ceil_secs(wait))).unwrap_or_default()
+}
 
 impl ErrorMetadata for RateLimitError {
 	fn code(&self) -> &'static str {
@@ -626,7 +1041,54 @@ impl ErrorMetadata for RateLimitError {
 	}
 
 	fn context(&self) -> Option<String> {
-		Some(format!("Requests: {}/{} in {} seconds", self.requests, self.limit, self.window_secs))
+		let mut parts = vec![format!(
+			"Requests: {}/{} in {} seconds",
+			self.requests, self.limit, self.window_secs
+		)];
+		if let Some(wait) = self.retry_after {
+			parts.push(format!("retry after {}s", ceil_secs(wait)));
+		}
+		if let Some(reset_at) = self.reset_at {
+			let reset_at = chrono::DateTime::<chrono::Utc>::from(reset_at)
+				.to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+			parts.push(format!("resets at {reset_at}"));
+		}
+		Some(parts.join(", "))
+	}
+}
+
+// ============================================================================
+// I/O Errors
+// ============================================================================
+
+impl ErrorMetadata for std::io::Error {
+	fn code(&self) -> &'static str {
+		"GMN-IO-001"
+	}
+
+	fn hint(&self) -> Option<&str> {
+		use std::io::ErrorKind;
+
+		match self.kind() {
+			ErrorKind::NotFound => Some("Check that the path exists"),
+			ErrorKind::PermissionDenied => {
+				Some("Check the file permissions and the user the process runs as")
+			}
+			ErrorKind::AlreadyExists => Some("Remove the existing file or choose another path"),
+			ErrorKind::StorageFull => Some("Free up disk space and try again"),
+			ErrorKind::TimedOut | ErrorKind::Interrupted | ErrorKind::WouldBlock => {
+				Some("The operation may succeed if retried")
+			}
+			ErrorKind::ConnectionRefused
+			| ErrorKind::ConnectionReset
+			| ErrorKind::ConnectionAborted
+			| ErrorKind::BrokenPipe => Some("Check that the other end is running and reachable"),
+			_ => None,
+		}
+	}
+
+	fn context(&self) -> Option<String> {
+		Some(format!("Kind: {:?}", self.kind()))
 	}
 }
 
@@ -635,59 +1097,270 @@ impl ErrorMetadata for RateLimitError {
 // ============================================================================
 
 /// API/network errors (placeholder for future implementation)
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
 pub enum ApiError {
 	/// Network request failed
-	NetworkError(String),
+	#[error("Network error: {message}")]
+	NetworkError {
+		/// What went wrong, e.g. the host that could not be reached
+		message: String,
+		/// The HTTP client error, when there is one
+		#[source]
+		source: Option<BoxError>,
+	},
 	/// Invalid response
+	#[error("Invalid response: {0}")]
 	InvalidResponse(String),
 	/// Server error
+	#[error("Server error ({status}): {}", server_message_preview(.message))]
 	ServerError {
 		/// HTTP status code
 		status: u16,
 		/// Error message from server
 		message: String,
 	},
+	/// Request did not complete before its deadline
+	#[error("Request to {endpoint} timed out after {timeout_ms}ms")]
+	Timeout {
+		/// Endpoint that was called
+		endpoint: String,
+		/// Configured timeout in milliseconds
+		timeout_ms: u64,
+	},
 }
 
-impl fmt::Display for ApiError {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		match self {
-			Self::NetworkError(msg) => write!(f, "Network error: {}", msg),
-			Self::InvalidResponse(msg) => write!(f, "Invalid response: {}", msg),
-			Self::ServerError { status, message } => {
-				write!(f, "Server error ({}): {}", status, message)
-			}
-		}
-	}
+/// Longest server message echoed in [`ApiError::ServerError`]'s display, in characters.
+///
+/// Servers sometimes answer with whole HTML pages; anything past this is cut off.
+const MAX_SERVER_MESSAGE_CHARS: usize = 500;
+
+/// The server message as shown in [`ApiError::ServerError`]'s display.
+fn server_message_preview(message: &str) -> String {
+	let shown = truncate_chars(message, MAX_SERVER_MESSAGE_CHARS);
+	let ellipsis = if shown.len() < message.len() { "…" } else { "" };
+	format!("{shown}{ellipsis}")
 }
 
-impl std::error::Error for ApiError {}
-
 impl ErrorMetadata for ApiError {
 	fn code(&self) -> &'static str {
 		match self {
-			Self::NetworkError(_) => "GMN-API-001",
+			Self::NetworkError { .. } => "GMN-API-001",
 			Self::InvalidResponse(_) => "GMN-API-002",
 			Self::ServerError { .. } => "GMN-API-003",
+			Self::Timeout { .. } => "GMN-API-004",
 		}
 	}
 
 	fn hint(&self) -> Option<&str> {
 		match self {
-			Self::NetworkError(_) => Some("Check network connectivity and firewall settings"),
+			Self::NetworkError { .. } => Some("Check network connectivity and firewall settings"),
 			Self::InvalidResponse(_) => Some("The API response format may have changed"),
 			Self::ServerError { status, .. } if *status >= 500 => {
 				Some("Server is experiencing issues, try again later")
 			}
 			Self::ServerError { .. } => Some("Check request parameters and authentication"),
+			Self::Timeout { .. } => Some("Retry with backoff or raise the request timeout"),
 		}
 	}
 
 	fn context(&self) -> Option<String> {
 		match self {
 			Self::ServerError { status, .. } => Some(format!("HTTP status: {}", status)),
+			Self::Timeout { endpoint, .. } => Some(format!("Endpoint: {endpoint}")),
 			_ => None,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_structured_database_variants() {
+		let err = DatabaseError::ConstraintViolation {
+			constraint: "users_email_key".to_string(),
+			table: "users".to_string(),
+		};
+		assert_eq!(err.code(), "GMN-DB-005");
+		assert_eq!(err.context().as_deref(), Some("Constraint: users_email_key on table users"));
+
+		let err = GmnError::from(DatabaseError::PoolTimeout { waited_ms: 5000, pool_size: 10 });
+		assert!(matches!(err, GmnError::Database(DatabaseError::PoolTimeout { .. })));
+		assert_eq!(err.code(), "GMN-DB-004");
+		assert_eq!(err.context().as_deref(), Some("Waited 5000ms on a pool of 10 connections"));
+
+		let err = GmnError::from(DatabaseError::Deadlock { table: None });
+		assert_eq!(err.code(), "GMN-DB-006");
+		assert_eq!(err.context(), None);
+	}
+
+	#[test]
+	fn test_structured_auth_and_api_variants() {
+		let err = GmnError::from(AuthError::MissingScope {
+			required: "billing:write".to_string(),
+			granted: vec!["billing:read".to_string(), "profile".to_string()],
+		});
+		assert!(matches!(err, GmnError::Auth(AuthError::MissingScope { .. })));
+		assert_eq!(err.code(), "GMN-AUTH-005");
+		assert_eq!(err.context().as_deref(), Some("Granted scopes: billing:read, profile"));
+
+		let err = GmnError::from(AuthError::InvalidToken { reason: "bad signature".to_string() });
+		assert_eq!(err.code(), "GMN-AUTH-004");
+
+		let err = GmnError::from(ApiError::Timeout {
+			endpoint: "/v1/orders".to_string(),
+			timeout_ms: 3000,
+		});
+		assert!(matches!(err, GmnError::Api(ApiError::Timeout { .. })));
+		assert_eq!(err.code(), "GMN-API-004");
+		assert_eq!(err.context().as_deref(), Some("Endpoint: /v1/orders"));
+	}
+
+	#[test]
+	fn test_server_error_message_truncates_on_char_boundary() {
+		let err = ApiError::ServerError { status: 502, message: "é".repeat(600) };
+		let shown = err.to_string();
+
+		assert!(shown.starts_with("Server error (502): é"));
+		assert!(shown.ends_with("é…"));
+		assert_eq!(shown.chars().filter(|&c| c == 'é').count(), MAX_SERVER_MESSAGE_CHARS);
+	}
+
+	#[test]
+	fn test_flatten_walks_source_chain() {
+		let err = GmnError::from(ConfigError::InvalidOutputPath {
+			path: "/nope/logs".to_string(),
+			source: std::io::Error::new(std::io::ErrorKind::NotFound, "no such directory"),
+		});
+
+		assert_eq!(
+			err.flatten(),
+			vec![
+				(
+					"GMN-CFG-003".to_string(),
+					"Configuration error: Invalid output path: /nope/logs".to_string()
+				),
+				(FOREIGN_ERROR_CODE.to_string(), "no such directory".to_string()),
+			]
+		);
+	}
+
+	#[test]
+	fn test_flatten_without_causes() {
+		let err = GmnError::Internal("boom".to_string());
+		assert_eq!(
+			err.flatten(),
+			vec![("GMN-000".to_string(), "Internal error: boom".to_string())]
+		);
+	}
+
+	#[test]
+	fn test_to_json_carries_display_fields_and_causes() {
+		let err = GmnError::from(ConfigError::InvalidOutputPath {
+			path: "/nope/logs".to_string(),
+			source: std::io::Error::new(std::io::ErrorKind::NotFound, "no such directory"),
+		});
+		let json = err.to_json();
+
+		assert_eq!(json["code"], "GMN-CFG-003");
+		assert_eq!(json["title"], "Configuration Error");
+		assert_eq!(json["message"], "Configuration error: Invalid output path: /nope/logs");
+		assert_eq!(json["causes"], serde_json::json!(["no such directory"]));
+		assert!(json["hint"].is_string());
+
+		let internal = GmnError::Internal("boom".to_string()).to_json();
+		assert!(internal["context"].is_null() && internal["hint"].is_null());
+
+		let line = err.to_json_string();
+		assert!(!line.contains('\n'));
+		assert!(line.contains(r#""code":"GMN-CFG-003""#), "{line}");
+	}
+
+	#[test]
+	fn test_connection_and_network_errors_keep_their_source() {
+		let err = GmnError::from(DatabaseError::ConnectionFailed {
+			message: "localhost:5432".to_string(),
+			source: Some(Box::new(std::io::Error::new(
+				std::io::ErrorKind::ConnectionRefused,
+				"connection refused",
+			))),
+		});
+		assert_eq!(err.code(), "GMN-DB-001");
+		assert_eq!(
+			err.flatten()[1],
+			(FOREIGN_ERROR_CODE.to_string(), "connection refused".to_string())
+		);
+
+		let err = ApiError::NetworkError { message: "api.example.com".to_string(), source: None };
+		assert_eq!(err.to_string(), "Network error: api.example.com");
+		assert!(std::error::Error::source(&err).is_none());
+	}
+
+	#[test]
+	fn test_retryable_classification() {
+		let retryable: Vec<GmnError> = vec![
+			ApiError::ServerError { status: 503, message: String::new() }.into(),
+			ApiError::NetworkError { message: "reset".to_string(), source: None }.into(),
+			RateLimitError::new(11, 10, 60).into(),
+			DatabaseError::ConnectionFailed { message: "down".to_string(), source: None }.into(),
+			DatabaseError::TransactionFailed("serialization failure".to_string()).into(),
+		];
+		assert!(retryable.iter().all(GmnError::is_retryable));
+
+		let permanent: Vec<GmnError> = vec![
+			ApiError::ServerError { status: 404, message: String::new() }.into(),
+			ConfigError::InvalidLogLevel { level: "loud".to_string() }.into(),
+			AuthError::TokenExpired.into(),
+			GenericError::Validation { field: "email".to_string(), message: "empty".to_string() }
+				.into(),
+		];
+		assert!(!permanent.iter().any(GmnError::is_retryable));
+
+		assert_eq!(retryable[2].retry_after(), Some(std::time::Duration::from_secs(60)));
+		assert_eq!(retryable[0].retry_after(), None);
+	}
+
+	#[test]
+	fn test_rate_limit_retry_information() {
+		let plain = RateLimitError::new(11, 10, 60);
+		assert_eq!(plain.to_string(), "Rate limit exceeded: 11 requests in 60 seconds (limit: 10)");
+		assert_eq!(plain.context().as_deref(), Some("Requests: 11/10 in 60 seconds"));
+
+		let reset_at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_800_000_000);
+		let err = RateLimitError::new(11, 10, 60)
+			.with_retry_after(std::time::Duration::from_millis(11_200))
+			.with_reset_at(reset_at);
+		assert!(err.to_string().ends_with("(limit: 10), retry after 12s"), "{err}");
+		assert_eq!(
+			err.context().as_deref(),
+			Some("Requests: 11/10 in 60 seconds, retry after 12s, resets at 2027-01-15T08:00:00Z")
+		);
+		assert_eq!(
+			GmnError::from(err).retry_after(),
+			Some(std::time::Duration::from_millis(11_200))
+		);
+	}
+
+	#[test]
+	fn test_io_error_converts_with_kind_metadata() {
+		fn read_missing() -> Result<String> {
+			Ok(std::fs::read_to_string("/definitely/not/here/gmn.toml")?)
+		}
+
+		let err = read_missing().err();
+		assert!(matches!(err, Some(GmnError::Io(_))), "{err:?}");
+		let err = err.as_ref();
+		assert_eq!(err.map(GmnError::code), Some("GMN-IO-001"));
+		assert_eq!(err.and_then(GmnError::hint), Some("Check that the path exists"));
+		assert_eq!(err.and_then(GmnError::context).as_deref(), Some("Kind: NotFound"));
+		assert_eq!(err.map(GmnError::is_retryable), Some(false));
+
+		// As a source, an io::Error is still reported as foreign
+		let nested = GmnError::from(ConfigError::InvalidOutputPath {
+			path: "/nope".to_string(),
+			source: std::io::Error::other("disk on fire"),
+		});
+		assert_eq!(nested.flatten()[1].0, FOREIGN_ERROR_CODE);
+	}
+}
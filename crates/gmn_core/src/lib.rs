@@ -37,7 +37,8 @@
 //!
 //! fn main() -> gmn_core::Result<()> {
 //!     let config = TracingConfig::development();
-//!     init_tracing_with_config(config)?;
+//!     // Keep the guard alive so buffered file logs are flushed on exit
+//!     let _tracing = init_tracing_with_config(config)?;
 //!     Ok(())
 //! }
 //! ```
@@ -92,5 +93,8 @@ pub mod prelude {
 	pub use tracing::{debug, error, info, trace, warn};
 
 	// Re-export instrumentation macros
-	pub use crate::{log_event, measure_duration, trace_operation};
+	pub use crate::{
+		instrument_api, instrument_db, log_event, measure_duration, measure_duration_async,
+		measure_duration_result, measure_duration_timed, operation_reported, trace_operation,
+	};
 }
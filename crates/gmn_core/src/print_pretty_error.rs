@@ -15,8 +15,17 @@
 //!   - [`print_pretty_info`]
 //!   - [`print_pretty_success`]
 //!   - [`print_pretty_input`]
+//! - `_to` variants of all of the above that write to any [`std::io::Write`]
+//!   target instead of stderr, e.g. [`print_pretty_message_to`]
 //! - A string-rendering function for tests/snapshots/log piping:
 //!   - [`pretty_message_to_string`]
+//! - Key/value variants that render context as an aligned `• key: value` list:
+//!   - [`print_pretty_message_kv`]
+//!   - [`pretty_message_kv_to_string`]
+//! - A fixed-width renderer for deterministic snapshot tests:
+//!   - [`render_with_width`]
+//! - A single-line JSON form of the same message for structured log collectors:
+//!   - [`pretty_message_to_json`]
 //!
 //! # Message model
 //!
@@ -26,18 +35,30 @@
 //! - **title**: short, high-signal headline
 //! - **code**: stable short identifier (`AUTH-401`, `DB-003`, etc.)
 //! - **message**: primary body text
-//! - **context** *(optional)*: additional surrounding detail
+//! - **causes** *(optional)*: the error's source chain, drawn as dimmed `caused by:`
+//!   lines under the message (filled in by `error_display`)
+//! - **context** *(optional)*: additional surrounding detail; long blocks are cut after
+//!   [`PrettyOptions::max_context_lines`] lines with a `… (N more lines)` marker
 //! - **hint** *(optional)*: actionable next step for the user
 //! - **location** *(optional)*: source/function/path indicator
+//! - **command** *(optional)*: shell command that remediates the issue, shown under the
+//!   hint (see [`print_pretty_warning_with_command`])
 //!
 //! # Output behavior
 //!
-//! - Printed output goes to **stderr** (not stdout).
+//! - Printed output goes to **stderr** (not stdout), unless a callback is installed
+//!   with [`set_sink`].
 //! - Width is auto-computed and constrained by terminal width when available.
 //! - Content is wrapped to stay readable in narrow terminals.
-//! - ANSI styling is used for terminals that support color.
+//! - ANSI styling is used when stderr is a terminal; `NO_COLOR` turns it off and
+//!   `FORCE_COLOR` forces it on (see [`ColorMode`]).
+//! - Box glyphs (rounded, square or ASCII; ASCII by default on stderr when
+//!   `TERM=dumb`), border coloring, section dividers, surrounding blank lines and
+//!   header icons (see [`set_icons`]; ASCII by default on stderr in a non-UTF-8 locale)
+//!   can be tuned process-wide through [`PrettyOptions`] and [`set_pretty_options`].
 //! - The printing API intentionally does not return an error; rendering failures are
-//!   treated as best-effort display concerns.
+//!   treated as best-effort display concerns. [`try_print_pretty_message`] and the
+//!   `_to` variants report them instead.
 //!
 //! # Usage examples
 //!
@@ -108,18 +129,35 @@
 //!
 //! **Author:** @gabmacn
 
-use chrono::Local;
-use colored::*; // Keep for user content styling
-use std::io::{self, Write};
+use chrono::{Local, SecondsFormat, Utc};
+use serde::Serialize;
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Arc, PoisonError, RwLock};
 use terminal_size::{Height, Width, terminal_size};
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 use wrap_ansi::{WrapOptions, wrap_ansi};
 
 // CONSTANTS
 const MIN_CONTENT_WIDTH: usize = 40;
 const MAX_CONTENT_WIDTH: usize = 140;
 const FRAME_MARGIN: usize = 4; // breathing room around content
+const MIN_FIXED_WIDTH: usize = 40; // narrowest caller-chosen frame that still lays out
+const CAUSE_PREFIX: &str = "caused by: ";
+const DEFAULT_MAX_CONTEXT_LINES: usize = 20; // generous: only blobs get truncated
+const CONTEXT_BULLET: &str = "• ";
+const CONTEXT_KEY_GAP: usize = 1; // spacing between a context key's colon and its value
 const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const DIM: &str = "\x1b[2m";
+const ITALIC: &str = "\x1b[3m";
+const UNDERLINE: &str = "\x1b[4m";
+const BLACK: &str = "\x1b[30m";
+const YELLOW: &str = "\x1b[33m";
+const BRIGHT_BLUE: &str = "\x1b[94m";
+const BRIGHT_CYAN: &str = "\x1b[96m";
+const MUTED: &str = "\x1b[38;2;100;100;100m"; // section labels, bullets, the code
+const SUBTLE: &str = "\x1b[38;2;150;150;150m"; // context and cause text
 
 /// Semantic message level used to select styling and label.
 ///
@@ -138,7 +176,8 @@ const RESET: &str = "\x1b[0m";
 /// - [`PrettyMessageLevel::Info`]: neutral informational update
 /// - [`PrettyMessageLevel::Success`]: positive completion/confirmation
 /// - [`PrettyMessageLevel::Input`]: prompt-like interaction context
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PrettyMessageLevel {
 	/// Use for fatal conditions, failed operations, validation errors, or anything that should stand out immediately.
 	Error,
@@ -152,108 +191,605 @@ pub enum PrettyMessageLevel {
 	Input,
 }
 
+/// Whether frame borders are drawn in the level color or as plain glyphs.
+///
+/// Icons and labels keep their level color either way; only the box outline and the
+/// horizontal dividers are affected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+	/// Borders use the level color (the default).
+	#[default]
+	Colored,
+	/// Borders are emitted without color escapes, e.g. when they clash with a colored prompt.
+	Plain,
+}
+
+/// Whether internal dividers separate the sections of a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SectionStyle {
+	/// Horizontal dividers after the header and before the hint (the default).
+	#[default]
+	Dividers,
+	/// No internal dividers; sections are separated by blank rows only, for a denser look.
+	Compact,
+}
+
+/// How much vertical space surrounds a frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Spacing {
+	/// A blank line before the top border and after the bottom border (the default).
+	#[default]
+	Comfortable,
+	/// No leading blank line and a single trailing newline, so consecutive frames stack.
+	Tight,
+}
+
+/// Whether ANSI escape sequences (colors, bold, italics) are emitted at all.
+///
+/// With color disabled, borders fall back to plain glyphs and every escape sequence,
+/// including any embedded in message text, is removed from the output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+	/// Follow the environment (the default): color is forced when `FORCE_COLOR` is set,
+	/// otherwise disabled when `NO_COLOR` is set, otherwise enabled only when stderr is a
+	/// terminal. Output rendered to strings and writers is not subject to the terminal
+	/// check.
+	#[default]
+	Auto,
+	/// Always emit color, regardless of environment and terminal.
+	Always,
+	/// Never emit color.
+	Never,
+}
+
+/// Glyph set used to draw the box around a frame.
+///
+/// Only the glyphs change; colors stay per level.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoxStyle {
+	/// Rounded corners, or ASCII when printing to stderr with `TERM=dumb` (the default).
+	#[default]
+	Auto,
+	/// Rounded Unicode corners: `╭ ╮ ╰ ╯`.
+	Rounded,
+	/// Square Unicode corners: `┌ ┐ └ ┘`.
+	Square,
+	/// Plain ASCII (`+ - |`) for consoles that mangle box-drawing characters.
+	Ascii,
+}
+
+impl BoxStyle {
+	/// The style to draw on a terminal whose `TERM` environment variable is `term`,
+	/// swapping [`Auto`](Self::Auto) for ASCII on dumb terminals.
+	fn for_term(self, term: Option<&str>) -> Self {
+		if self == Self::Auto && term == Some("dumb") { Self::Ascii } else { self }
+	}
+
+	const fn glyphs(self) -> BoxGlyphs {
+		match self {
+			Self::Auto | Self::Rounded => ROUNDED_GLYPHS,
+			Self::Square => SQUARE_GLYPHS,
+			Self::Ascii => ASCII_GLYPHS,
+		}
+	}
+}
+
+/// Whether and how the header shows the time a message was rendered.
+///
+/// Formats use `chrono`'s `strftime` syntax. An invalid format hides the timestamp
+/// instead of failing the render.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampConfig {
+	/// No timestamp; the header space goes to the title.
+	None,
+	/// Local time in the given format (the default is `Local("%H:%M:%S")`).
+	Local(&'static str),
+	/// UTC time in the given format.
+	Utc(&'static str),
+}
+
+impl Default for TimestampConfig {
+	fn default() -> Self {
+		Self::Local("%H:%M:%S")
+	}
+}
+
+/// Header icon shown for each message level.
+///
+/// The default set uses single-width symbols (`✖ ⚠ ℹ ✔ ⌨`); [`IconSet::ascii`] and
+/// [`IconSet::bracketed`] are safe for any font and [`IconSet::emoji`] suits terminals
+/// with color emoji.
+///
+/// When printing to stderr, the default symbols fall back to [`IconSet::bracketed`] if
+/// the locale (`LC_ALL`, `LC_CTYPE`, then `LANG`) is set to something other than UTF-8,
+/// since such terminals usually draw them as boxes. Any other set is used as-is, and
+/// renders to strings or writers always use the configured set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconSet {
+	/// Icon for [`PrettyMessageLevel::Error`].
+	pub error: &'static str,
+	/// Icon for [`PrettyMessageLevel::Warning`].
+	pub warning: &'static str,
+	/// Icon for [`PrettyMessageLevel::Info`].
+	pub info: &'static str,
+	/// Icon for [`PrettyMessageLevel::Success`].
+	pub success: &'static str,
+	/// Icon for [`PrettyMessageLevel::Input`].
+	pub input: &'static str,
+}
+
+impl IconSet {
+	/// The default symbol icons: `✖ ⚠ ℹ ✔ ⌨`.
+	pub const fn symbols() -> Self {
+		Self { error: "✖", warning: "⚠", info: "ℹ", success: "✔", input: "⌨" }
+	}
+
+	/// Plain ASCII icons for fonts without symbol glyphs: `X ! i + >`.
+	pub const fn ascii() -> Self {
+		Self { error: "X", warning: "!", info: "i", success: "+", input: ">" }
+	}
+
+	/// Bracketed ASCII icons: `[x] [!] [i] [ok] [>]`.
+	pub const fn bracketed() -> Self {
+		Self { error: "[x]", warning: "[!]", info: "[i]", success: "[ok]", input: "[>]" }
+	}
+
+	/// Color emoji icons: `❌ ⚠️ ℹ️ ✅ ⌨️`.
+	pub const fn emoji() -> Self {
+		Self {
+			error: "❌",
+			warning: "⚠\u{fe0f}",
+			info: "ℹ\u{fe0f}",
+			success: "✅",
+			input: "⌨\u{fe0f}",
+		}
+	}
+
+	/// The set to draw under `locale`, swapping the default symbols for ASCII when the
+	/// locale is not UTF-8.
+	fn for_locale(self, locale: Option<&str>) -> Self {
+		if self == Self::symbols() && !locale_is_utf8(locale) { Self::bracketed() } else { self }
+	}
+
+	const fn for_level(self, level: PrettyMessageLevel) -> &'static str {
+		match level {
+			PrettyMessageLevel::Error => self.error,
+			PrettyMessageLevel::Warning => self.warning,
+			PrettyMessageLevel::Info => self.info,
+			PrettyMessageLevel::Success => self.success,
+			PrettyMessageLevel::Input => self.input,
+		}
+	}
+}
+
+impl Default for IconSet {
+	fn default() -> Self {
+		Self::symbols()
+	}
+}
+
+/// The effective locale: the first non-empty of `LC_ALL`, `LC_CTYPE` and `LANG`.
+fn current_locale() -> Option<String> {
+	["LC_ALL", "LC_CTYPE", "LANG"]
+		.into_iter()
+		.filter_map(|name| std::env::var(name).ok())
+		.find(|value| !value.is_empty())
+}
+
+/// Whether a locale name such as `en_US.UTF-8` uses UTF-8.
+///
+/// An unset locale counts as UTF-8: it is the norm on Windows and in minimal containers
+/// whose terminals still render Unicode fine.
+fn locale_is_utf8(locale: Option<&str>) -> bool {
+	locale.is_none_or(|locale| {
+		let locale = locale.to_ascii_lowercase();
+		locale.contains("utf-8") || locale.contains("utf8")
+	})
+}
+
+/// Rendering settings applied to every pretty message.
+///
+/// Options are process-wide: install them once with [`set_pretty_options`] and every
+/// `print_pretty_*` call and [`pretty_message_to_string`] picks them up.
+///
+/// ## Example
+///
+/// ```rust
+/// use gmn_core::print_pretty_error::{set_pretty_options, BorderStyle, PrettyOptions};
+///
+/// set_pretty_options(PrettyOptions::new().with_border_style(BorderStyle::Plain));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PrettyOptions {
+	/// How the box outline is colored.
+	pub border_style: BorderStyle,
+	/// Whether sections are separated by dividers.
+	pub section_style: SectionStyle,
+	/// Blank lines around the frame.
+	pub spacing: Spacing,
+	/// Whether ANSI styling is emitted.
+	pub color_mode: ColorMode,
+	/// Header icon per level.
+	pub icons: IconSet,
+	/// Whether `path:line` locations are emitted as clickable OSC 8 hyperlinks.
+	pub hyperlinks: bool,
+	/// Glyphs used for the box outline.
+	pub box_style: BoxStyle,
+	/// Header timestamp.
+	pub timestamp: TimestampConfig,
+	/// Most wrapped context lines shown before the rest is elided; `None` shows all.
+	pub max_context_lines: Option<usize>,
+	/// Exact row width (borders included) instead of sizing to content and terminal.
+	pub fixed_width: Option<usize>,
+}
+
+impl PrettyOptions {
+	/// Create options with the default rendering behavior.
+	pub const fn new() -> Self {
+		Self {
+			border_style: BorderStyle::Colored,
+			section_style: SectionStyle::Dividers,
+			spacing: Spacing::Comfortable,
+			color_mode: ColorMode::Auto,
+			icons: IconSet::symbols(),
+			hyperlinks: false,
+			box_style: BoxStyle::Auto,
+			timestamp: TimestampConfig::Local("%H:%M:%S"),
+			max_context_lines: Some(DEFAULT_MAX_CONTEXT_LINES),
+			fixed_width: None,
+		}
+	}
+
+	/// Builder method to set the border style.
+	#[must_use]
+	pub const fn with_border_style(mut self, border_style: BorderStyle) -> Self {
+		self.border_style = border_style;
+		self
+	}
+
+	/// Builder method to set the section style.
+	#[must_use]
+	pub const fn with_section_style(mut self, section_style: SectionStyle) -> Self {
+		self.section_style = section_style;
+		self
+	}
+
+	/// Builder method to set the spacing around the frame.
+	#[must_use]
+	pub const fn with_spacing(mut self, spacing: Spacing) -> Self {
+		self.spacing = spacing;
+		self
+	}
+
+	/// Builder method to set when color is emitted.
+	#[must_use]
+	pub const fn with_color_mode(mut self, color_mode: ColorMode) -> Self {
+		self.color_mode = color_mode;
+		self
+	}
+
+	/// Builder method to set the header icons.
+	#[must_use]
+	pub const fn with_icons(mut self, icons: IconSet) -> Self {
+		self.icons = icons;
+		self
+	}
+
+	/// Builder method to turn `path:line` locations into OSC 8 hyperlinks.
+	///
+	/// Supporting terminals (`iTerm2`, `WezTerm`, the VS Code terminal, ...) then open the
+	/// source file when the location is clicked. Off by default.
+	#[must_use]
+	pub const fn with_hyperlinks(mut self, hyperlinks: bool) -> Self {
+		self.hyperlinks = hyperlinks;
+		self
+	}
+
+	/// Builder method to set the box drawing style.
+	#[must_use]
+	pub const fn with_box_style(mut self, box_style: BoxStyle) -> Self {
+		self.box_style = box_style;
+		self
+	}
+
+	/// Builder method to set or disable the header timestamp.
+	#[must_use]
+	pub const fn with_timestamp(mut self, timestamp: TimestampConfig) -> Self {
+		self.timestamp = timestamp;
+		self
+	}
+
+	/// Builder method to cap how many context lines are shown.
+	///
+	/// Beyond the cap, context is cut and a dimmed `… (N more lines)` marker is drawn.
+	/// Pass `None` to always show the whole context.
+	#[must_use]
+	pub const fn with_max_context_lines(mut self, max_context_lines: Option<usize>) -> Self {
+		self.max_context_lines = max_context_lines;
+		self
+	}
+
+	/// Builder method to pin every frame to an exact width.
+	///
+	/// With `Some(width)`, terminal size and `COLUMNS` are ignored and each row, borders
+	/// included, is `width` columns wide (at least 40). `None` restores auto-sizing.
+	#[must_use]
+	pub const fn with_fixed_width(mut self, fixed_width: Option<usize>) -> Self {
+		self.fixed_width = fixed_width;
+		self
+	}
+}
+
+static PRETTY_OPTIONS: RwLock<PrettyOptions> = RwLock::new(PrettyOptions::new());
+
+/// Install the rendering options used by all subsequent pretty messages.
+pub fn set_pretty_options(options: PrettyOptions) {
+	*PRETTY_OPTIONS.write().unwrap_or_else(PoisonError::into_inner) = options;
+}
+
+/// Currently installed rendering options.
+pub fn pretty_options() -> PrettyOptions {
+	*PRETTY_OPTIONS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Replace the header icons of the installed options, keeping every other setting.
+///
+/// ```rust
+/// use gmn_core::print_pretty_error::{set_icons, IconSet};
+///
+/// set_icons(IconSet::ascii());
+/// ```
+pub fn set_icons(icons: IconSet) {
+	PRETTY_OPTIONS.write().unwrap_or_else(PoisonError::into_inner).icons = icons;
+}
+
+/// Callback receiving every rendered pretty message in place of stderr.
+///
+/// It is called with the message level and the fully rendered frame, ANSI styling
+/// included. No lock is held during the call, so the sink may print pretty messages
+/// itself or replace the installed sink.
+pub type PrettySink = Arc<dyn Fn(PrettyMessageLevel, &str) + Send + Sync>;
+
+static PRETTY_SINK: RwLock<Option<PrettySink>> = RwLock::new(None);
+
+/// Route all subsequent pretty messages to `sink` instead of stderr.
+///
+/// Meant for embedders (GUI apps, IDE plugins) that show messages in their own log
+/// pane. Replaces any previously installed sink.
+pub fn set_sink(sink: PrettySink) {
+	*PRETTY_SINK.write().unwrap_or_else(PoisonError::into_inner) = Some(sink);
+}
+
+/// Remove the installed sink so pretty messages go back to stderr.
+pub fn clear_sink() {
+	*PRETTY_SINK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
 #[derive(Clone, Copy)]
 struct Frame {
-	border_v: &'static str,
-	border_tl: &'static str,
-	border_tr: &'static str,
-	border_bl: &'static str,
-	border_br: &'static str,
-	border_joint_left: &'static str,
-	border_joint_right: &'static str,
+	glyphs: BoxGlyphs,
 	line_color: &'static str,
 	line_dim_color: &'static str,
 	icon: &'static str,
 	label: &'static str,
-	label_color: Color,
+	label_color: &'static str,
 }
 
-// A static buffer of spaces for zero-allocation padding
-static SPACES: &str = "                                                                        ";
+/// Glyphs used to draw the box outline and dividers.
+#[derive(Clone, Copy)]
+struct BoxGlyphs {
+	top_left: &'static str,
+	top_right: &'static str,
+	bottom_left: &'static str,
+	bottom_right: &'static str,
+	vertical: &'static str,
+	horizontal: &'static str,
+	joint_left: &'static str,
+	joint_right: &'static str,
+}
 
-// Pre-rendered frames per message level to keep the hot path allocation-free.
-const FRAME_ERROR: Frame = Frame {
-	border_v: "\x1b[31m│\x1b[0m",
-	border_tl: "\x1b[31m╭\x1b[0m",
-	border_tr: "\x1b[31m╮\x1b[0m",
-	border_bl: "\x1b[31m╰\x1b[0m",
-	border_br: "\x1b[31m╯\x1b[0m",
-	border_joint_left: "\x1b[31m├\x1b[0m",
-	border_joint_right: "\x1b[31m┤\x1b[0m",
-	line_color: "\x1b[31m",
-	line_dim_color: "\x1b[31;2m",
-	icon: "✖",
-	label: "ERROR",
-	label_color: Color::Red,
+const ROUNDED_GLYPHS: BoxGlyphs = BoxGlyphs {
+	top_left: "╭",
+	top_right: "╮",
+	bottom_left: "╰",
+	bottom_right: "╯",
+	vertical: "│",
+	horizontal: "─",
+	joint_left: "├",
+	joint_right: "┤",
 };
 
-const FRAME_WARNING: Frame = Frame {
-	border_v: "\x1b[33m│\x1b[0m",
-	border_tl: "\x1b[33m╭\x1b[0m",
-	border_tr: "\x1b[33m╮\x1b[0m",
-	border_bl: "\x1b[33m╰\x1b[0m",
-	border_br: "\x1b[33m╯\x1b[0m",
-	border_joint_left: "\x1b[33m├\x1b[0m",
-	border_joint_right: "\x1b[33m┤\x1b[0m",
-	line_color: "\x1b[33m",
-	line_dim_color: "\x1b[33;2m",
-	icon: "⚠",
-	label: "WARNING",
-	label_color: Color::Yellow,
+const SQUARE_GLYPHS: BoxGlyphs = BoxGlyphs {
+	top_left: "┌",
+	top_right: "┐",
+	bottom_left: "└",
+	bottom_right: "┘",
+	..ROUNDED_GLYPHS
 };
 
-const FRAME_INFO: Frame = Frame {
-	border_v: "\x1b[34m│\x1b[0m",
-	border_tl: "\x1b[34m╭\x1b[0m",
-	border_tr: "\x1b[34m╮\x1b[0m",
-	border_bl: "\x1b[34m╰\x1b[0m",
-	border_br: "\x1b[34m╯\x1b[0m",
-	border_joint_left: "\x1b[34m├\x1b[0m",
-	border_joint_right: "\x1b[34m┤\x1b[0m",
-	line_color: "\x1b[34m",
-	line_dim_color: "\x1b[34;2m",
-	icon: "ℹ",
-	label: "INFO",
-	label_color: Color::Blue,
+const ASCII_GLYPHS: BoxGlyphs = BoxGlyphs {
+	top_left: "+",
+	top_right: "+",
+	bottom_left: "+",
+	bottom_right: "+",
+	vertical: "|",
+	horizontal: "-",
+	joint_left: "+",
+	joint_right: "+",
 };
 
-const FRAME_SUCCESS: Frame = Frame {
-	border_v: "\x1b[32m│\x1b[0m",
-	border_tl: "\x1b[32m╭\x1b[0m",
-	border_tr: "\x1b[32m╮\x1b[0m",
-	border_bl: "\x1b[32m╰\x1b[0m",
-	border_br: "\x1b[32m╯\x1b[0m",
-	border_joint_left: "\x1b[32m├\x1b[0m",
-	border_joint_right: "\x1b[32m┤\x1b[0m",
-	line_color: "\x1b[32m",
-	line_dim_color: "\x1b[32;2m",
-	icon: "✔",
-	label: "SUCCESS",
-	label_color: Color::Green,
-};
+/// Per-level colors and label, combined with glyphs and icons into a [`Frame`].
+#[derive(Clone, Copy)]
+struct LevelStyle {
+	line_color: &'static str,
+	line_dim_color: &'static str,
+	label: &'static str,
+}
 
-const FRAME_INPUT: Frame = Frame {
-	border_v: "\x1b[36m│\x1b[0m",
-	border_tl: "\x1b[36m╭\x1b[0m",
-	border_tr: "\x1b[36m╮\x1b[0m",
-	border_bl: "\x1b[36m╰\x1b[0m",
-	border_br: "\x1b[36m╯\x1b[0m",
-	border_joint_left: "\x1b[36m├\x1b[0m",
-	border_joint_right: "\x1b[36m┤\x1b[0m",
-	line_color: "\x1b[36m",
-	line_dim_color: "\x1b[36;2m",
-	icon: "⌨",
-	label: "INPUT",
-	label_color: Color::Cyan,
-};
+/// Borrowed view over every section of a message, shared by measurement and rendering.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct MessageParts<'a> {
+	pub(crate) title: &'a str,
+	pub(crate) code: &'a str,
+	pub(crate) message: &'a str,
+	pub(crate) context: Option<&'a str>,
+	pub(crate) context_fields: &'a [(&'a str, &'a str)],
+	pub(crate) hint: Option<&'a str>,
+	pub(crate) location: Option<&'a str>,
+	pub(crate) command: Option<&'a str>,
+	/// Header timestamp, formatted once per render so measurement and drawing agree.
+	pub(crate) timestamp: Option<&'a str>,
+	/// Source-chain messages shown as `caused by:` lines, outermost first.
+	pub(crate) causes: &'a [String],
+}
 
-fn frame_for(level: PrettyMessageLevel) -> &'static Frame {
-	match level {
-		PrettyMessageLevel::Error => &FRAME_ERROR,
-		PrettyMessageLevel::Warning => &FRAME_WARNING,
-		PrettyMessageLevel::Info => &FRAME_INFO,
-		PrettyMessageLevel::Success => &FRAME_SUCCESS,
-		PrettyMessageLevel::Input => &FRAME_INPUT,
+impl MessageParts<'_> {
+	const fn has_context(&self) -> bool {
+		self.context.is_some() || !self.context_fields.is_empty()
+	}
+}
+
+// A static buffer of spaces for zero-allocation padding
+static SPACES: &str = "                                                                        ";
+
+// Per-level styles; frames are assembled from static parts to keep the hot path allocation-free.
+const LEVEL_ERROR: LevelStyle =
+	LevelStyle { line_color: "\x1b[31m", line_dim_color: "\x1b[31;2m", label: "ERROR" };
+
+const LEVEL_WARNING: LevelStyle =
+	LevelStyle { line_color: "\x1b[33m", line_dim_color: "\x1b[33;2m", label: "WARNING" };
+
+const LEVEL_INFO: LevelStyle =
+	LevelStyle { line_color: "\x1b[34m", line_dim_color: "\x1b[34;2m", label: "INFO" };
+
+const LEVEL_SUCCESS: LevelStyle =
+	LevelStyle { line_color: "\x1b[32m", line_dim_color: "\x1b[32;2m", label: "SUCCESS" };
+
+const LEVEL_INPUT: LevelStyle =
+	LevelStyle { line_color: "\x1b[36m", line_dim_color: "\x1b[36;2m", label: "INPUT" };
+
+/// Decide whether ANSI styling is emitted.
+///
+/// `force_color` and `no_color` are the values of the `FORCE_COLOR` and `NO_COLOR`
+/// environment variables. Per no-color.org, `NO_COLOR` only counts when non-empty;
+/// `FORCE_COLOR` counts unless empty, `0` or `false`, and wins over `NO_COLOR`.
+fn ansi_enabled(
+	mode: ColorMode,
+	force_color: Option<&str>,
+	no_color: Option<&str>,
+	is_tty: bool,
+) -> bool {
+	match mode {
+		ColorMode::Always => true,
+		ColorMode::Never => false,
+		ColorMode::Auto => {
+			if color_forced(force_color) {
+				true
+			} else if no_color.is_some_and(|value| !value.is_empty()) {
+				false
+			} else {
+				is_tty
+			}
+		}
+	}
+}
+
+/// Whether a `FORCE_COLOR` value asks for color.
+fn color_forced(force_color: Option<&str>) -> bool {
+	force_color.is_some_and(|value| !matches!(value, "" | "0" | "false"))
+}
+
+/// Resolve [`ansi_enabled`] against the process environment.
+fn resolve_ansi(mode: ColorMode, is_tty: bool) -> bool {
+	let force_color = std::env::var("FORCE_COLOR").ok();
+	let no_color = std::env::var("NO_COLOR").ok();
+	ansi_enabled(mode, force_color.as_deref(), no_color.as_deref(), is_tty)
+}
+
+/// `text` wrapped in the raw escape sequences `styles`, then a reset.
+///
+/// Frames are styled through this rather than `colored`, whose own terminal detection
+/// would disagree with [`ColorMode`]; when color is off, [`render_frame`] strips every
+/// escape from the finished frame. A reset inside `text` re-opens `styles`, so styled
+/// user content does not end the outer style early.
+fn paint(text: &str, styles: &[&str]) -> String {
+	let open = styles.concat();
+	format!("{open}{}{RESET}", text.replace(RESET, &format!("{RESET}{open}")))
+}
+
+/// Remove every terminal escape sequence from `s`.
+fn strip_escapes(s: &str) -> String {
+	let mut out = String::with_capacity(s.len());
+	let mut rest = s;
+
+	while !rest.is_empty() {
+		if let Some(esc_len) = escape_len(rest) {
+			rest = &rest[esc_len..];
+			continue;
+		}
+		let text_end = rest.find('\x1b').unwrap_or(rest.len());
+		out.push_str(&rest[..text_end]);
+		rest = &rest[text_end..];
+	}
+
+	out
+}
+
+/// Render a full frame into `writer`, at `content_width` when given or sized to the
+/// content otherwise, honoring the color mode.
+///
+/// Glyphs and icons are drawn exactly as `options` configures them; callers printing to
+/// a terminal resolve `TERM` and the locale into `options` first.
+fn render_frame(
+	writer: &mut impl Write,
+	level: PrettyMessageLevel,
+	parts: &MessageParts<'_>,
+	options: PrettyOptions,
+	content_width: Option<usize>,
+	is_tty: bool,
+) -> io::Result<()> {
+	let ansi = resolve_ansi(options.color_mode, is_tty);
+	let frame = frame_for(level, options, ansi, options.box_style.glyphs());
+
+	// Read the clock once: width measurement and the drawn header must see the same text
+	let timestamp = format_timestamp(options.timestamp);
+	let parts = &MessageParts { timestamp: timestamp.as_deref(), ..*parts };
+	let content_width = content_width
+		.or_else(|| options.fixed_width.map(fixed_content_width))
+		.unwrap_or_else(|| compute_content_width(&frame, parts, options.max_context_lines));
+
+	if ansi {
+		return render_pretty_message(writer, &frame, content_width, parts, options);
+	}
+
+	let mut buffer = Vec::new();
+	render_pretty_message(&mut buffer, &frame, content_width, parts, options)?;
+	writer.write_all(strip_escapes(&String::from_utf8_lossy(&buffer)).as_bytes())
+}
+
+fn frame_for(
+	level: PrettyMessageLevel,
+	options: PrettyOptions,
+	ansi: bool,
+	glyphs: BoxGlyphs,
+) -> Frame {
+	let style = match level {
+		PrettyMessageLevel::Error => LEVEL_ERROR,
+		PrettyMessageLevel::Warning => LEVEL_WARNING,
+		PrettyMessageLevel::Info => LEVEL_INFO,
+		PrettyMessageLevel::Success => LEVEL_SUCCESS,
+		PrettyMessageLevel::Input => LEVEL_INPUT,
+	};
+	// Plain borders drop the line colors only: icon and label keep the level color.
+	let colored_borders = ansi && options.border_style == BorderStyle::Colored;
+
+	Frame {
+		glyphs,
+		line_color: if colored_borders { style.line_color } else { "" },
+		line_dim_color: if colored_borders { style.line_dim_color } else { "" },
+		icon: options.icons.for_level(level),
+		label: style.label,
+		label_color: style.line_color,
 	}
 }
 
@@ -280,46 +816,109 @@ fn measure_lines(max_len: &mut usize, indent: usize, text: &str) {
 	}
 }
 
+/// Current time formatted for the header, or `None` when timestamps are disabled.
+fn format_timestamp(config: TimestampConfig) -> Option<String> {
+	use std::fmt::Write as _;
+
+	let mut stamp = String::new();
+	let written = match config {
+		TimestampConfig::None => return None,
+		TimestampConfig::Local(format) => write!(stamp, "{}", Local::now().format(format)),
+		TimestampConfig::Utc(format) => write!(stamp, "{}", Utc::now().format(format)),
+	};
+	written.ok().map(|()| stamp)
+}
+
+/// Unstyled right-hand side of the header: `[code] timestamp `.
+fn header_right_text(code: &str, timestamp: Option<&str>) -> String {
+	timestamp.map_or_else(|| format!("[{code}] "), |stamp| format!("[{code}] {stamp} "))
+}
+
 fn compute_content_width(
 	frame: &Frame,
-	title: &str,
-	code: &str,
-	message: &str,
-	context: Option<&str>,
-	hint: Option<&str>,
-	location: Option<&str>,
+	parts: &MessageParts<'_>,
+	max_context_lines: Option<usize>,
 ) -> usize {
-	let timestamp = Local::now().format("%H:%M:%S");
-	let title_up = title.to_uppercase();
+	let cap = max_content_width();
+	let title_up = parts.title.to_uppercase();
 
 	let header_left_len = visible_len(&format!(" {} {} {}", frame.icon, frame.label, title_up));
-	let header_right_len = visible_len(&format!("[{}] {} ", code, timestamp));
+	let header_right_len = visible_len(&header_right_text(parts.code, parts.timestamp));
 	let mut max_len = header_left_len + header_right_len;
 
-	if let Some(loc) = location {
+	if let Some(loc) = parts.location {
 		max_len = max_len.max(visible_len(&format!("   ‣at {}", loc)));
 	}
 
-	measure_lines(&mut max_len, 2, message);
+	measure_lines(&mut max_len, 2, parts.message);
 
-	if let Some(ctx) = context {
-		measure_lines(&mut max_len, 2, ctx);
+	for (depth, cause) in parts.causes.iter().enumerate() {
+		measure_lines(&mut max_len, cause_indent(depth) + CAUSE_PREFIX.len(), cause);
 	}
 
-	if let Some(h) = hint {
+	if let Some(ctx) = parts.context {
+		// Only the wrapped lines the renderer keeps count toward the width. Wrapping at
+		// the widest frame gives the same lines: a line wrapped there widens the frame to
+		// that width, and a narrower frame wraps none of them.
+		let lines = wrap_text(ctx, context_wrap_width(cap), wrap_options());
+		let kept = max_context_lines.map_or(lines.len(), |max| lines.len().min(max));
+		for line in &lines[..kept] {
+			measure_lines(&mut max_len, 2, line);
+		}
+	}
+
+	let value_indent = context_value_indent(parts.context_fields);
+	for (_, value) in parts.context_fields {
+		measure_lines(&mut max_len, 2 + value_indent, value);
+	}
+
+	if let Some(h) = parts.hint {
 		measure_lines(&mut max_len, 5, h);
 	}
 
+	if let Some(cmd) = parts.command {
+		measure_lines(&mut max_len, 7, cmd);
+	}
+
 	// Add breathing room and clamp to sensible bounds / terminal width
-	let desired = max_len.saturating_add(FRAME_MARGIN);
-	let term_cap = terminal_width_limit();
-	desired.clamp(MIN_CONTENT_WIDTH, MAX_CONTENT_WIDTH).min(term_cap.max(MIN_CONTENT_WIDTH))
+	max_len.saturating_add(FRAME_MARGIN).clamp(MIN_CONTENT_WIDTH, cap)
+}
+
+/// Widest content width a measured frame can get, given the terminal width.
+fn max_content_width() -> usize {
+	MAX_CONTENT_WIDTH.min(terminal_width_limit().max(MIN_CONTENT_WIDTH))
+}
+
+/// Column budget of the message and context lines inside a frame of `content_width`.
+fn context_wrap_width(content_width: usize) -> usize {
+	content_width.saturating_sub(4).max(10)
+}
+
+/// ANSI-aware word wrapping shared by the measuring and drawing passes.
+fn wrap_options() -> WrapOptions {
+	WrapOptions::builder().word_wrap(true).hard_wrap(false).build()
+}
+
+/// Content width for a frame whose full rows, borders included, are `width` wide.
+const fn fixed_content_width(width: usize) -> usize {
+	let width = if width < MIN_FIXED_WIDTH { MIN_FIXED_WIDTH } else { width };
+	width - 2
+}
+
+/// Column (relative to the row indent) where every context field value starts.
+///
+/// Fits the bullet, the widest key and its colon, so all values line up.
+fn context_value_indent(fields: &[(&str, &str)]) -> usize {
+	let key_width = fields.iter().map(|(key, _)| visible_len(key)).max().unwrap_or(0);
+	visible_len(CONTEXT_BULLET) + key_width + 1 + CONTEXT_KEY_GAP
 }
 
 /// Measure visible display width of a potentially ANSI-styled string.
 ///
-/// This function walks characters and ignores terminal CSI color sequences
-/// (`\x1b[...m`) while counting printable width, using Unicode display width rules.
+/// This function skips terminal escape sequences (`\x1b[...m` colors and friends)
+/// and measures the remaining text one grapheme cluster at a time, using Unicode
+/// display width rules. Measuring whole clusters means combining marks add nothing
+/// and an emoji with a skin-tone modifier counts once rather than twice.
 ///
 /// It is used to:
 ///
@@ -330,25 +929,141 @@ fn compute_content_width(
 /// The implementation is allocation-free and optimized for hot rendering paths.
 fn visible_len(s: &str) -> usize {
 	let mut len = 0;
-	let mut in_esc = false;
+	let mut rest = s;
 
-	for c in s.chars() {
-		if c == '\x1b' {
-			in_esc = true;
+	while !rest.is_empty() {
+		if let Some(esc_len) = escape_len(rest) {
+			rest = &rest[esc_len..];
 			continue;
 		}
 
-		if in_esc {
-			if c == 'm' {
-				in_esc = false;
+		let text_end = rest.find('\x1b').unwrap_or(rest.len());
+		len += rest[..text_end].graphemes(true).map(grapheme_width).sum::<usize>();
+		rest = &rest[text_end..];
+	}
+
+	len
+}
+
+/// Display width of one grapheme cluster.
+///
+/// A zero-width-joiner sequence such as 👨‍👩‍👧 is drawn as a single emoji, so it never
+/// counts for more than two columns, however many wide parts it joins.
+fn grapheme_width(cluster: &str) -> usize {
+	let width = UnicodeWidthStr::width(cluster);
+	if cluster.contains('\u{200d}') { width.min(2) } else { width }
+}
+
+/// Byte length of the terminal escape sequence at the start of `s`, if there is one.
+///
+/// Recognizes CSI sequences (`ESC [ ... final`) and OSC sequences (`ESC ] ... BEL` or
+/// `ESC ] ... ESC \`); any other escape is treated as `ESC` plus one character.
+/// Unterminated sequences swallow the rest of the string.
+fn escape_len(s: &str) -> Option<usize> {
+	let body = s.strip_prefix('\x1b')?;
+
+	if let Some(params) = body.strip_prefix('[') {
+		let end = params.find(|c: char| ('@'..='~').contains(&c)).map_or(params.len(), |i| i + 1);
+		return Some(2 + end);
+	}
+
+	if let Some(payload) = body.strip_prefix(']') {
+		let end = match (payload.find('\x07'), payload.find("\x1b\\")) {
+			(Some(bel), Some(st)) if st < bel => st + 2,
+			(Some(bel), _) => bel + 1,
+			(None, Some(st)) => st + 2,
+			(None, None) => payload.len(),
+		};
+		return Some(2 + end);
+	}
+
+	Some(1 + body.chars().next().map_or(0, char::len_utf8))
+}
+
+/// Wrap `text` to `width` columns without ever splitting a grapheme cluster.
+///
+/// Words are wrapped by `wrap_ansi`. Any single word that is still wider than
+/// `width` (long paths, runs of emoji) is then broken between grapheme clusters, so
+/// combining accents and emoji modifiers always stay attached to their base.
+fn wrap_text(text: &str, width: usize, wrap_opts: WrapOptions) -> Vec<String> {
+	let wrapped = wrap_ansi(text, width, Some(wrap_opts));
+	let mut lines = Vec::new();
+
+	for line in wrapped.lines() {
+		if visible_len(line) <= width {
+			lines.push(line.to_string());
+		} else {
+			break_graphemes(line, width, &mut lines);
+		}
+	}
+
+	lines
+}
+
+/// Break an over-wide styled line at grapheme boundaries, pushing the pieces to `out`.
+///
+/// Styles active at a break are reset at the end of the piece and re-opened at the
+/// start of the next one, so colors survive the break.
+fn break_graphemes(line: &str, width: usize, out: &mut Vec<String>) {
+	let mut current = String::new();
+	let mut current_width = 0;
+	let mut active_style = String::new();
+	let mut rest = line;
+
+	while !rest.is_empty() {
+		if let Some(esc_len) = escape_len(rest) {
+			let esc = &rest[..esc_len];
+			if esc == RESET || esc == "\x1b[m" {
+				active_style.clear();
+			} else {
+				active_style.push_str(esc);
 			}
+			current.push_str(esc);
+			rest = &rest[esc_len..];
 			continue;
 		}
 
-		len += UnicodeWidthChar::width(c).unwrap_or(0);
+		let text_end = rest.find('\x1b').unwrap_or(rest.len());
+		for grapheme in rest[..text_end].graphemes(true) {
+			let cluster_width = grapheme_width(grapheme);
+			if current_width > 0 && current_width + cluster_width > width {
+				if !active_style.is_empty() {
+					current.push_str(RESET);
+				}
+				out.push(std::mem::replace(&mut current, active_style.clone()));
+				current_width = 0;
+			}
+			current.push_str(grapheme);
+			current_width += cluster_width;
+		}
+		rest = &rest[text_end..];
 	}
 
-	len
+	out.push(current);
+}
+
+/// Truncate `s` to at most `max` characters, cutting only on a char boundary.
+///
+/// Returns a borrowed prefix of `s`; no allocation and no risk of splitting a
+/// multi-byte UTF-8 sequence the way byte slicing would.
+pub fn truncate_chars(s: &str, max: usize) -> &str {
+	s.char_indices().nth(max).map_or(s, |(byte_idx, _)| &s[..byte_idx])
+}
+
+/// Truncate `s` to at most `cols` terminal columns, cutting only between grapheme clusters.
+///
+/// Wide characters count as two columns and combining marks stay with their base, so
+/// the result never ends in half an emoji or a dangling accent. Expects plain text;
+/// escape sequences are measured as ordinary characters.
+pub fn truncate_display_width(s: &str, cols: usize) -> &str {
+	let mut width = 0;
+	for (byte_idx, grapheme) in s.grapheme_indices(true) {
+		width += grapheme_width(grapheme);
+		if width > cols {
+			return &s[..byte_idx];
+		}
+	}
+	s
 }
 
 /// Write `width` spaces into the provided writer without allocating a new string.
@@ -373,17 +1088,21 @@ fn draw_row(
 	let vis_len = visible_len(content);
 	let padding = content_width.saturating_sub(vis_len);
 
-	writer.write_all(frame.border_v.as_bytes())?;
+	write_glyph(writer, frame.line_color, frame.glyphs.vertical, 1)?;
 	writer.write_all(content.as_bytes())?;
 	write_padding(writer, padding)?;
-	writer.write_all(frame.border_v.as_bytes())?;
+	write_glyph(writer, frame.line_color, frame.glyphs.vertical, 1)?;
 	writer.write_all(b"\n")
 }
 
-fn write_horizontal(writer: &mut impl Write, color: &str, width: usize) -> io::Result<()> {
+/// Write `glyph` `count` times in `color`, resetting afterwards unless `color` is empty.
+fn write_glyph(writer: &mut impl Write, color: &str, glyph: &str, count: usize) -> io::Result<()> {
 	writer.write_all(color.as_bytes())?;
-	for _ in 0..width {
-		writer.write_all("─".as_bytes())?;
+	for _ in 0..count {
+		writer.write_all(glyph.as_bytes())?;
+	}
+	if color.is_empty() {
+		return Ok(());
 	}
 	writer.write_all(RESET.as_bytes())
 }
@@ -394,131 +1113,259 @@ fn draw_horizontal_line(
 	content_width: usize,
 	joints: bool,
 ) -> io::Result<()> {
-	if joints {
-		writer.write_all(frame.border_joint_left.as_bytes())?;
-		write_horizontal(writer, frame.line_dim_color, content_width)?;
-		writer.write_all(frame.border_joint_right.as_bytes())?;
-		writer.write_all(b"\n")
+	let glyphs = frame.glyphs;
+	let (left, right) = if joints {
+		(glyphs.joint_left, glyphs.joint_right)
 	} else {
-		writer.write_all(frame.border_v.as_bytes())?;
-		write_horizontal(writer, frame.line_dim_color, content_width)?;
-		writer.write_all(frame.border_v.as_bytes())?;
-		writer.write_all(b"\n")
+		(glyphs.vertical, glyphs.vertical)
+	};
+
+	write_glyph(writer, frame.line_color, left, 1)?;
+	write_glyph(writer, frame.line_dim_color, glyphs.horizontal, content_width)?;
+	write_glyph(writer, frame.line_color, right, 1)?;
+	writer.write_all(b"\n")
+}
+
+/// Left indent of the `caused by:` line for the cause at `depth` (0 = direct cause).
+const fn cause_indent(depth: usize) -> usize {
+	2 + 2 * depth
+}
+
+/// Draw each cause as a dimmed `caused by:` line, indented one step per level.
+///
+/// Long causes wrap with their continuation rows aligned after the prefix.
+fn draw_causes(
+	writer: &mut impl Write,
+	frame: &Frame,
+	content_width: usize,
+	causes: &[String],
+	wrap_opts: WrapOptions,
+) -> io::Result<()> {
+	for (depth, cause) in causes.iter().enumerate() {
+		let indent = cause_indent(depth);
+		let text_indent = indent + CAUSE_PREFIX.len();
+		let wrap_width = content_width.saturating_sub(text_indent + 2).max(10);
+		let styled_cause = paint(cause, &[ITALIC, SUBTLE]);
+
+		for (i, line) in wrap_text(&styled_cause, wrap_width, wrap_opts).iter().enumerate() {
+			let prefix = if i == 0 { CAUSE_PREFIX } else { "" };
+			let gap = text_indent - indent - prefix.len();
+			let row = format!("{:indent$}{}{:gap$}{line}", "", paint(prefix, &[MUTED]), "");
+			draw_row(writer, frame, content_width, &row)?;
+		}
 	}
+	Ok(())
 }
 
-// Allow because this is a non-exposed internal function with many args for flexibility.
-#[allow(clippy::too_many_arguments)]
-fn render_pretty_message(
-	handle: &mut impl Write,
+/// Draw key/value context fields as an aligned bulleted list of `• key: value` rows.
+///
+/// Keys are right-padded to the widest key so every value starts at the same column.
+/// Values that do not fit wrap onto continuation rows indented to that same column.
+fn draw_context_table(
+	writer: &mut impl Write,
 	frame: &Frame,
 	content_width: usize,
-	title: &str,
-	code: &str,
-	message: &str,
-	context: Option<&str>,
-	hint: Option<&str>,
-	location: Option<&str>,
+	fields: &[(&str, &str)],
+	wrap_opts: WrapOptions,
 ) -> io::Result<()> {
-	macro_rules! draw {
-        ($func:ident $(, $arg:expr )* ) => {
-            $func(handle, frame, content_width $(, $arg )* )?
-        };
-    }
+	let value_indent = context_value_indent(fields);
+	let value_wrap_width = content_width.saturating_sub(4 + value_indent).max(10);
 
-	handle.write_all(b"\n")?;
-	handle.write_all(frame.border_tl.as_bytes())?;
-	write_horizontal(handle, frame.line_color, content_width)?;
-	handle.write_all(frame.border_tr.as_bytes())?;
-	handle.write_all(b"\n")?;
+	for (key, value) in fields {
+		let default_value = paint(value, &[ITALIC, SUBTLE]);
+		let wrapped_value = wrap_text(&default_value, value_wrap_width, wrap_opts);
+		let mut lines = wrapped_value.iter();
+
+		let key_padding = value_indent - visible_len(CONTEXT_BULLET) - visible_len(key) - 1;
+		let first_row = format!(
+			"  {}{}{:key_padding$}{}",
+			paint(CONTEXT_BULLET, &[MUTED]),
+			paint(&format!("{key}:"), &[MUTED]),
+			"",
+			lines.next().map_or("", String::as_str)
+		);
+		draw_row(writer, frame, content_width, &first_row)?;
+
+		for line in lines {
+			draw_row(writer, frame, content_width, &format!("  {:value_indent$}{line}", ""))?;
+		}
+	}
+
+	Ok(())
+}
+
+/// `file://` URL for a `path:line` or `path:line:column` location, if it is one.
+///
+/// Relative paths are resolved against the current directory. Locations that do not
+/// end in a line number (e.g. `auth::middleware`) get no link.
+fn location_url(location: &str) -> Option<String> {
+	let mut segments = location.rsplitn(3, ':');
+	let last = segments.next()?;
+	let before_last = segments.next()?;
+
+	let (path, line) = match segments.next() {
+		Some(path) if before_last.parse::<u32>().is_ok() && last.parse::<u32>().is_ok() => {
+			(path, before_last)
+		}
+		_ if last.parse::<u32>().is_ok() => (location.rsplit_once(':')?.0, last),
+		_ => return None,
+	};
+
+	let absolute = std::path::absolute(path).ok()?;
+	Some(format!("file://{}#L{line}", absolute.display()))
+}
+
+/// Draw the header row: icon, label and title on the left, code and timestamp on the right.
+fn draw_header(
+	writer: &mut impl Write,
+	frame: &Frame,
+	content_width: usize,
+	parts: &MessageParts<'_>,
+) -> io::Result<()> {
+	let MessageParts { title, code, timestamp, .. } = *parts;
 
-	let timestamp = Local::now().format("%H:%M:%S");
 	let title_up = title.to_uppercase();
 	let left_part = format!(
 		" {} {} {}",
-		frame.icon.color(frame.label_color),
-		format!("{}:", frame.label).color(frame.label_color).bold(),
-		title_up.as_str().bold()
+		paint(frame.icon, &[frame.label_color]),
+		paint(&format!("{}:", frame.label), &[BOLD, frame.label_color]),
+		paint(&title_up, &[BOLD])
 	);
-	let right_part =
-		format!("[{}] {} ", code.bold(), timestamp).truecolor(100, 100, 100).to_string();
+	let stamp = timestamp.map_or(String::new(), |stamp| format!("{stamp} "));
+	let right_part = paint(&format!("[{}] {stamp}", paint(code, &[BOLD])), &[MUTED]);
 
 	let left_len = visible_len(&format!(" {} {}: {}", frame.icon, frame.label, title_up));
-	let right_len = visible_len(&format!("[{}] {} ", code, timestamp));
+	let right_len = visible_len(&header_right_text(code, timestamp));
 	let space_needed = content_width.saturating_sub(left_len + right_len);
 
-	handle.write_all(frame.border_v.as_bytes())?;
-	handle.write_all(left_part.as_bytes())?;
-	write_padding(handle, space_needed)?;
-	handle.write_all(right_part.as_bytes())?;
-	handle.write_all(frame.border_v.as_bytes())?;
+	write_glyph(writer, frame.line_color, frame.glyphs.vertical, 1)?;
+	writer.write_all(left_part.as_bytes())?;
+	write_padding(writer, space_needed)?;
+	writer.write_all(right_part.as_bytes())?;
+	write_glyph(writer, frame.line_color, frame.glyphs.vertical, 1)?;
+	writer.write_all(b"\n")
+}
+
+fn render_pretty_message(
+	handle: &mut impl Write,
+	frame: &Frame,
+	content_width: usize,
+	parts: &MessageParts<'_>,
+	options: PrettyOptions,
+) -> io::Result<()> {
+	macro_rules! draw {
+        ($func:ident $(, $arg:expr )* ) => {
+            $func(handle, frame, content_width $(, $arg )* )?
+        };
+    }
+
+	let comfortable = options.spacing == Spacing::Comfortable;
+
+	if comfortable {
+		handle.write_all(b"\n")?;
+	}
+	write_glyph(handle, frame.line_color, frame.glyphs.top_left, 1)?;
+	write_glyph(handle, frame.line_color, frame.glyphs.horizontal, content_width)?;
+	write_glyph(handle, frame.line_color, frame.glyphs.top_right, 1)?;
 	handle.write_all(b"\n")?;
 
+	draw!(draw_header, parts);
+
+	let MessageParts { message, context, context_fields, hint, location, command, .. } = *parts;
+
 	if let Some(loc) = location {
+		let styled_loc = paint(loc, &[BRIGHT_BLUE, UNDERLINE, ITALIC]);
+		let linked_loc = options.hyperlinks.then(|| location_url(loc)).flatten().map_or_else(
+			|| styled_loc.clone(),
+			|url| format!("\x1b]8;;{url}\x1b\\{styled_loc}\x1b]8;;\x1b\\"),
+		);
 		draw!(
 			draw_row,
-			&format!(
-				"   {}{} {}",
-				"‣".black(),
-				"at".black().italic(),
-				loc.bright_blue().underline().italic()
-			)
+			&format!("   {}{} {linked_loc}", paint("‣", &[BLACK]), paint("at", &[BLACK, ITALIC]))
 		);
 	}
 
-	draw!(draw_horizontal_line, false);
+	let dividers = options.section_style == SectionStyle::Dividers;
+	if dividers {
+		draw!(draw_horizontal_line, false);
+	}
 
 	// Setup ANSI-aware wrapping options
-	let wrap_opts = WrapOptions::builder().word_wrap(true).hard_wrap(false).build();
-	let wrap_width = content_width.saturating_sub(4).max(10);
+	let wrap_opts = wrap_options();
+	let wrap_width = context_wrap_width(content_width);
 
 	draw!(draw_row, "");
 
-	// 1. Wrap the main message
-	let wrapped_message = wrap_ansi(message, wrap_width, Some(wrap_opts.clone()));
-	for line in wrapped_message.lines() {
+	// 1. Wrap the main message and the causes behind it
+	for line in wrap_text(message, wrap_width, wrap_opts) {
 		draw!(draw_row, &format!("  {}", line));
 	}
+	draw!(draw_causes, parts.causes, wrap_opts);
 
 	draw!(draw_row, "");
 
 	// 2. Wrap the context
-	if let Some(ctx) = context {
-		draw!(draw_row, &format!("  {}", "CONTEXT:".truecolor(100, 100, 100)));
+	if parts.has_context() {
+		draw!(draw_row, &format!("  {}", paint("CONTEXT:", &[MUTED])));
 
-		// Apply the default style FIRST. wrap_ansi will distribute it across lines.
-		// If the user passed their own colors, their inner codes will override this!
-		let default_ctx = ctx.italic().truecolor(150, 150, 150).to_string();
-		let wrapped_context = wrap_ansi(&default_ctx, wrap_width, Some(wrap_opts.clone()));
+		if let Some(ctx) = context {
+			// Apply the default style FIRST. wrap_ansi will distribute it across lines.
+			// If the user passed their own colors, their inner codes will override this!
+			let default_ctx = paint(ctx, &[ITALIC, SUBTLE]);
+			let lines = wrap_text(&default_ctx, wrap_width, wrap_opts);
+			let shown = options.max_context_lines.map_or(lines.len(), |max| lines.len().min(max));
 
-		for line in wrapped_context.lines() {
-			// Print it raw! Let the embedded ANSI do the talking.
-			draw!(draw_row, &format!("  {}", line));
+			for line in &lines[..shown] {
+				// Print it raw! Let the embedded ANSI do the talking.
+				draw!(draw_row, &format!("  {}", line));
+			}
+			if shown < lines.len() {
+				let marker = format!("… ({} more lines)", lines.len() - shown);
+				draw!(draw_row, &format!("  {}", paint(&marker, &[MUTED, DIM])));
+			}
+		}
+
+		if !context_fields.is_empty() {
+			draw!(draw_context_table, context_fields, wrap_opts);
 		}
 		draw!(draw_row, "");
 	}
 
-	// 3. Wrap the hint
-	if let Some(h) = hint {
-		draw!(draw_horizontal_line, false);
-		draw!(draw_row, &format!("  {}", "➜  HINT".yellow().bold()));
+	// 3. Wrap the hint and its remediation command
+	if hint.is_some() || command.is_some() {
+		if dividers {
+			draw!(draw_horizontal_line, false);
+		}
+		draw!(draw_row, &format!("  {}", paint("➜  HINT", &[YELLOW, BOLD])));
 
 		let hint_wrap_width = content_width.saturating_sub(6).max(10);
 
-		// Default yellow.
-		let default_hint = h.yellow().to_string();
-		let wrapped_hint = wrap_ansi(&default_hint, hint_wrap_width, Some(wrap_opts));
+		if let Some(h) = hint {
+			// Default yellow.
+			let default_hint = paint(h, &[YELLOW]);
+			for line in wrap_text(&default_hint, hint_wrap_width, wrap_opts) {
+				// Print it raw!
+				draw!(draw_row, &format!("     {}", line));
+			}
+		}
 
-		for line in wrapped_hint.lines() {
-			// Print it raw!
-			draw!(draw_row, &format!("     {}", line));
+		// 4. The command gets a shell-prompt prefix and a code color of its own
+		if let Some(cmd) = command {
+			let command_wrap_width = content_width.saturating_sub(8).max(10);
+			let styled_command = paint(cmd, &[BRIGHT_CYAN]);
+			for (i, line) in
+				wrap_text(&styled_command, command_wrap_width, wrap_opts).iter().enumerate()
+			{
+				let prompt = if i == 0 { "$" } else { " " };
+				draw!(draw_row, &format!("     {} {line}", paint(prompt, &[MUTED])));
+			}
 		}
 	}
 
-	handle.write_all(frame.border_bl.as_bytes())?;
-	write_horizontal(handle, frame.line_color, content_width)?;
-	handle.write_all(frame.border_br.as_bytes())?;
-	handle.write_all(b"\n\n")?;
+	write_glyph(handle, frame.line_color, frame.glyphs.bottom_left, 1)?;
+	write_glyph(handle, frame.line_color, frame.glyphs.horizontal, content_width)?;
+	write_glyph(handle, frame.line_color, frame.glyphs.bottom_right, 1)?;
+	handle.write_all(if comfortable { b"\n\n" } else { b"\n" })?;
 
 	Ok(())
 }
@@ -547,6 +1394,7 @@ fn render_pretty_message(
 /// ## Return value
 ///
 /// Returns `()` and intentionally swallows rendering I/O errors (best-effort UX path).
+/// Use [`try_print_pretty_message`] to see them.
 ///
 /// ## Example
 ///
@@ -572,32 +1420,360 @@ pub fn print_pretty_message(
 	hint: Option<&str>,
 	location: Option<&str>,
 ) {
+	print_message_parts(
+		level,
+		&MessageParts { title, code, message, context, hint, location, ..MessageParts::default() },
+	);
+}
+
+/// Fallible form of [`print_pretty_message`].
+///
+/// Renders exactly the same frame to stderr (or the installed [`set_sink`] callback),
+/// but reports failures instead of dropping them, so long-running daemons can notice
+/// a closed pipe or a full disk behind a redirected stderr.
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if rendering, writing to stderr or flushing it fails.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gmn_core::print_pretty_error::{try_print_pretty_message, PrettyMessageLevel};
+///
+/// if let Err(err) = try_print_pretty_message(
+///     PrettyMessageLevel::Error,
+///     "Sync Failed",
+///     "SYNC-002",
+///     "The remote closed the connection.",
+///     None,
+///     None,
+///     None,
+/// ) {
+///     // stderr is gone; fall back to another channel
+///     eprintln!("could not report error: {err}");
+/// }
+/// ```
+pub fn try_print_pretty_message(
+	level: PrettyMessageLevel,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	try_print_message_parts(
+		level,
+		&MessageParts { title, code, message, context, hint, location, ..MessageParts::default() },
+	)
+}
+
+/// Print a pretty message whose context is a list of key/value fields to stderr.
+///
+/// The stderr counterpart of [`pretty_message_kv_to_string`]: each field is drawn under
+/// the CONTEXT header as a `• key: value` row, in the order given. Use this when an error
+/// has several discrete facts (file, line, expected vs actual) rather than one blob.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gmn_core::print_pretty_error::{print_pretty_message_kv, PrettyMessageLevel};
+///
+/// print_pretty_message_kv(
+///     PrettyMessageLevel::Error,
+///     "Invalid Config",
+///     "CFG-004",
+///     "The configuration file could not be parsed.",
+///     &[("file", "config.toml"), ("line", "12"), ("expected", "integer"), ("actual", "\"ten\"")],
+///     Some("Fix the value on line 12."),
+///     None,
+/// );
+/// ```
+pub fn print_pretty_message_kv(
+	level: PrettyMessageLevel,
+	title: &str,
+	code: &str,
+	message: &str,
+	context_fields: &[(&str, &str)],
+	hint: Option<&str>,
+	location: Option<&str>,
+) {
+	print_message_parts(
+		level,
+		&MessageParts {
+			title,
+			code,
+			message,
+			context_fields,
+			hint,
+			location,
+			..MessageParts::default()
+		},
+	);
+}
+
+/// Print any combination of message sections to stderr with the installed options.
+pub(crate) fn print_message_parts(level: PrettyMessageLevel, parts: &MessageParts<'_>) {
+	let _ = try_print_message_parts(level, parts);
+}
+
+/// Print message sections to stderr (or the installed sink), reporting write failures.
+fn try_print_message_parts(level: PrettyMessageLevel, parts: &MessageParts<'_>) -> io::Result<()> {
+	let sink = PRETTY_SINK.read().unwrap_or_else(PoisonError::into_inner).clone();
+	if let Some(sink) = sink {
+		let rendered = render_to_string(level, parts, pretty_options())?;
+		sink(level, &rendered);
+		return Ok(());
+	}
+
 	let stderr = io::stderr();
+	let is_tty = stderr.is_terminal();
 	// OPTIMIZATION 4: Lock once, wrap in Buffer
 	let mut handle = io::BufWriter::new(stderr.lock());
-	let frame = frame_for(level);
-	let content_width = compute_content_width(frame, title, code, message, context, hint, location);
-	let _ = render_pretty_message(
+	let options = pretty_options();
+	let options = PrettyOptions {
+		icons: options.icons.for_locale(current_locale().as_deref()),
+		box_style: options.box_style.for_term(std::env::var("TERM").ok().as_deref()),
+		..options
+	};
+	render_frame(&mut handle, level, parts, options, None, is_tty)?;
+	handle.flush()
+}
+
+/// Write a fully formatted pretty message to `writer` instead of stderr.
+///
+/// Same rendering as [`print_pretty_message`], for capturing frames in tests or
+/// sending them to stdout, a TUI pane or a file. Output is buffered internally and
+/// flushed before returning. Any installed [`set_sink`] callback is bypassed.
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if writing to or flushing `writer` fails.
+///
+/// ## Example
+///
+/// ```rust
+/// use gmn_core::print_pretty_error::{print_pretty_message_to, PrettyMessageLevel};
+///
+/// let mut out = Vec::new();
+/// print_pretty_message_to(
+///     &mut out,
+///     PrettyMessageLevel::Info,
+///     "Cache Warmup",
+///     "CACHE-INIT",
+///     "Preloading 42 templates into memory.",
+///     None,
+///     None,
+///     None,
+/// ).expect("should write");
+///
+/// assert!(String::from_utf8_lossy(&out).contains("CACHE-INIT"));
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn print_pretty_message_to<W: Write + ?Sized>(
+	writer: &mut W,
+	level: PrettyMessageLevel,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	let mut handle = io::BufWriter::new(writer);
+	render_frame(
 		&mut handle,
-		frame,
-		content_width,
-		title,
-		code,
-		message,
-		context,
-		hint,
-		location,
-	);
-	let _ = handle.flush();
+		level,
+		&MessageParts { title, code, message, context, hint, location, ..MessageParts::default() },
+		pretty_options(),
+		None,
+		true,
+	)?;
+	handle.flush()
 }
 
 /// Render a pretty message into a `String` instead of writing to stderr.
 ///
 /// Useful for:
 ///
-/// - tests and snapshot assertions
-/// - storing formatted terminal output
-/// - forwarding formatted text to alternate sinks
+/// - tests and snapshot assertions
+/// - storing formatted terminal output
+/// - forwarding formatted text to alternate sinks
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if rendering fails, or if UTF-8 conversion from the internal
+/// byte buffer fails.
+///
+/// ## Example
+///
+/// ```rust
+/// use gmn_core::print_pretty_error::{pretty_message_to_string, PrettyMessageLevel};
+///
+/// let text = pretty_message_to_string(
+///     PrettyMessageLevel::Info,
+///     "Server",
+///     "SRV-200",
+///     "HTTP server is listening on 127.0.0.1:3000.",
+///     None,
+///     None,
+///     Some("main"),
+/// ).expect("should render");
+///
+/// assert!(text.contains("INFO"));
+/// assert!(text.contains("SRV-200"));
+/// ```
+pub fn pretty_message_to_string(
+	level: PrettyMessageLevel,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<String> {
+	render_to_string(
+		level,
+		&MessageParts { title, code, message, context, hint, location, ..MessageParts::default() },
+		pretty_options(),
+	)
+}
+
+/// Render a pretty message whose context is a list of key/value fields.
+///
+/// Fields are drawn under the CONTEXT header as `• key: value` rows: keys are padded to
+/// the widest key so all values start at the same column, and long values wrap underneath
+/// their own value column. Field order is preserved.
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if rendering fails, or if UTF-8 conversion from the internal
+/// byte buffer fails.
+///
+/// ## Example
+///
+/// ```rust
+/// use gmn_core::print_pretty_error::{pretty_message_kv_to_string, PrettyMessageLevel};
+///
+/// let text = pretty_message_kv_to_string(
+///     PrettyMessageLevel::Error,
+///     "Invalid Config",
+///     "CFG-004",
+///     "The configuration file could not be parsed.",
+///     &[("file", "config.toml"), ("line", "12"), ("expected", "integer")],
+///     None,
+///     None,
+/// ).expect("should render");
+///
+/// assert!(text.contains("config.toml"));
+/// ```
+pub fn pretty_message_kv_to_string(
+	level: PrettyMessageLevel,
+	title: &str,
+	code: &str,
+	message: &str,
+	context_fields: &[(&str, &str)],
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<String> {
+	render_to_string(
+		level,
+		&MessageParts {
+			title,
+			code,
+			message,
+			context_fields,
+			hint,
+			location,
+			..MessageParts::default()
+		},
+		pretty_options(),
+	)
+}
+
+/// Render a pretty message as a single-line JSON object instead of a drawn box.
+///
+/// Takes the same arguments as [`pretty_message_to_string`], so a call site can pick
+/// between the two, e.g. JSON when stderr is not a TTY. The object carries `timestamp`
+/// (RFC 3339, UTC), `level`, `title`, `code` and `message`, plus `context`, `hint` and
+/// `location` when given. ANSI styling in the inputs is stripped.
+///
+/// ## Example
+///
+/// ```rust
+/// use gmn_core::print_pretty_error::{pretty_message_to_json, PrettyMessageLevel};
+///
+/// let line = pretty_message_to_json(
+///     PrettyMessageLevel::Error,
+///     "Database Error",
+///     "GMN-DB-001",
+///     "Connection refused.",
+///     None,
+///     Some("Is the database running?"),
+///     None,
+/// );
+///
+/// assert!(line.contains(r#""level":"error""#));
+/// assert!(!line.contains('\n'));
+/// ```
+#[must_use]
+pub fn pretty_message_to_json(
+	level: PrettyMessageLevel,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> String {
+	let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+	message_json(
+		level,
+		&MessageParts { title, code, message, context, hint, location, ..MessageParts::default() },
+		&timestamp,
+	)
+}
+
+/// One message as it is serialized by [`pretty_message_to_json`].
+#[derive(Serialize)]
+struct JsonMessage<'a> {
+	timestamp: &'a str,
+	level: PrettyMessageLevel,
+	title: String,
+	code: String,
+	message: String,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	context: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	hint: Option<String>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	location: Option<String>,
+}
+
+fn message_json(level: PrettyMessageLevel, parts: &MessageParts<'_>, timestamp: &str) -> String {
+	let json = JsonMessage {
+		timestamp,
+		level,
+		title: strip_escapes(parts.title),
+		code: strip_escapes(parts.code),
+		message: strip_escapes(parts.message),
+		context: parts.context.map(strip_escapes),
+		hint: parts.hint.map(strip_escapes),
+		location: parts.location.map(strip_escapes),
+	};
+	// Plain strings and a unit enum: serialization cannot fail
+	serde_json::to_string(&json).unwrap_or_default()
+}
+
+/// Render a pretty message at an exact, caller-chosen width.
+///
+/// Intended for snapshot/golden-file tests: terminal size, `COLUMNS`, `TERM` and the
+/// locale are ignored, and every row of the frame, borders included, is exactly `width`
+/// columns wide, so the output is identical on every machine. Content that does not fit
+/// is wrapped. Widths below 40 are raised to 40.
+///
+/// To pin the width of every message instead, see [`PrettyOptions::with_fixed_width`].
 ///
 /// ## Errors
 ///
@@ -607,22 +1783,24 @@ pub fn print_pretty_message(
 /// ## Example
 ///
 /// ```rust
-/// use gmn_core::print_pretty_error::{pretty_message_to_string, PrettyMessageLevel};
+/// use gmn_core::print_pretty_error::{render_with_width, PrettyMessageLevel};
 ///
-/// let text = pretty_message_to_string(
+/// let text = render_with_width(
+///     60,
 ///     PrettyMessageLevel::Info,
-///     "Server",
+///     "Server Ready",
 ///     "SRV-200",
-///     "HTTP server is listening on 127.0.0.1:3000.",
+///     "HTTP server is listening on 0.0.0.0:8080.",
+///     None,
 ///     None,
 ///     None,
-///     Some("main"),
 /// ).expect("should render");
 ///
-/// assert!(text.contains("INFO"));
 /// assert!(text.contains("SRV-200"));
 /// ```
-pub fn pretty_message_to_string(
+#[allow(clippy::too_many_arguments)]
+pub fn render_with_width(
+	width: usize,
 	level: PrettyMessageLevel,
 	title: &str,
 	code: &str,
@@ -631,24 +1809,32 @@ pub fn pretty_message_to_string(
 	hint: Option<&str>,
 	location: Option<&str>,
 ) -> io::Result<String> {
+	let parts =
+		MessageParts { title, code, message, context, hint, location, ..MessageParts::default() };
 	let mut buffer = Vec::new();
-	let frame = frame_for(level);
-	let content_width = compute_content_width(frame, title, code, message, context, hint, location);
-	render_pretty_message(
+	render_frame(
 		&mut buffer,
-		frame,
-		content_width,
-		title,
-		code,
-		message,
-		context,
-		hint,
-		location,
+		level,
+		&parts,
+		pretty_options(),
+		Some(fixed_content_width(width)),
+		true,
 	)?;
 
 	String::from_utf8(buffer).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
 }
 
+fn render_to_string(
+	level: PrettyMessageLevel,
+	parts: &MessageParts<'_>,
+	options: PrettyOptions,
+) -> io::Result<String> {
+	let mut buffer = Vec::new();
+	render_frame(&mut buffer, level, parts, options, None, true)?;
+
+	String::from_utf8(buffer).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
 /// Convenience wrapper for [`PrettyMessageLevel::Error`].
 ///
 /// Use this for fatal conditions, failed operations, validation errors, or anything
@@ -716,6 +1902,50 @@ pub fn print_pretty_warning(
 	);
 }
 
+/// Print a warning whose hint is followed by a copy-pasteable remediation command.
+///
+/// The command is rendered on its own row below the hint, prefixed with a `$` shell
+/// prompt and drawn in a code color so it stands apart from the prose.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gmn_core::print_pretty_error::print_pretty_warning_with_command;
+///
+/// print_pretty_warning_with_command(
+///     "Debug Logs Disabled",
+///     "LOG-LEVEL",
+///     "Debug events are filtered out at the current log level.",
+///     None,
+///     Some("Raise the log level and restart the service."),
+///     "export GMN_LOG_LEVEL=debug",
+///     Some("bootstrap::tracing"),
+/// );
+/// ```
+pub fn print_pretty_warning_with_command(
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	command: &str,
+	location: Option<&str>,
+) {
+	print_message_parts(
+		PrettyMessageLevel::Warning,
+		&MessageParts {
+			title,
+			code,
+			message,
+			context,
+			hint,
+			location,
+			command: Some(command),
+			..MessageParts::default()
+		},
+	);
+}
+
 /// Convenience wrapper for [`PrettyMessageLevel::Info`].
 ///
 /// Use this for neutral, operator-friendly progress or status updates.
@@ -765,3 +1995,837 @@ pub fn print_pretty_input(
 ) {
 	print_pretty_message(PrettyMessageLevel::Input, title, code, message, context, hint, location);
 }
+
+/// [`print_pretty_error`] writing to `writer`; see [`print_pretty_message_to`].
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if writing to or flushing `writer` fails.
+pub fn print_pretty_error_to<W: Write + ?Sized>(
+	writer: &mut W,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	let level = PrettyMessageLevel::Error;
+	print_pretty_message_to(writer, level, title, code, message, context, hint, location)
+}
+
+/// [`print_pretty_warning`] writing to `writer`; see [`print_pretty_message_to`].
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if writing to or flushing `writer` fails.
+pub fn print_pretty_warning_to<W: Write + ?Sized>(
+	writer: &mut W,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	let level = PrettyMessageLevel::Warning;
+	print_pretty_message_to(writer, level, title, code, message, context, hint, location)
+}
+
+/// [`print_pretty_info`] writing to `writer`; see [`print_pretty_message_to`].
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if writing to or flushing `writer` fails.
+pub fn print_pretty_info_to<W: Write + ?Sized>(
+	writer: &mut W,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	let level = PrettyMessageLevel::Info;
+	print_pretty_message_to(writer, level, title, code, message, context, hint, location)
+}
+
+/// [`print_pretty_success`] writing to `writer`; see [`print_pretty_message_to`].
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if writing to or flushing `writer` fails.
+pub fn print_pretty_success_to<W: Write + ?Sized>(
+	writer: &mut W,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	let level = PrettyMessageLevel::Success;
+	print_pretty_message_to(writer, level, title, code, message, context, hint, location)
+}
+
+/// [`print_pretty_input`] writing to `writer`; see [`print_pretty_message_to`].
+///
+/// ## Errors
+///
+/// Returns [`io::Error`] if writing to or flushing `writer` fails.
+pub fn print_pretty_input_to<W: Write + ?Sized>(
+	writer: &mut W,
+	title: &str,
+	code: &str,
+	message: &str,
+	context: Option<&str>,
+	hint: Option<&str>,
+	location: Option<&str>,
+) -> io::Result<()> {
+	let level = PrettyMessageLevel::Input;
+	print_pretty_message_to(writer, level, title, code, message, context, hint, location)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use colored::Colorize;
+
+	/// Strip CSI escape sequences so tests can reason about visible columns.
+	fn strip_ansi(s: &str) -> String {
+		let mut out = String::with_capacity(s.len());
+		let mut in_esc = false;
+		for c in s.chars() {
+			if c == '\x1b' {
+				in_esc = true;
+			} else if in_esc {
+				if c == 'm' {
+					in_esc = false;
+				}
+			} else {
+				out.push(c);
+			}
+		}
+		out
+	}
+
+	fn sample_parts() -> MessageParts<'static> {
+		MessageParts {
+			title: "Sample",
+			code: "SMP-001",
+			message: "Something happened.",
+			context: Some("Some context."),
+			hint: Some("Try again."),
+			..MessageParts::default()
+		}
+	}
+
+	/// Visible column at which `needle` starts in `line`, if present.
+	fn column_of(line: &str, needle: &str) -> Option<usize> {
+		line.find(needle).map(|byte_idx| visible_len(&line[..byte_idx]))
+	}
+
+	#[test]
+	fn test_context_fields_align_values() -> io::Result<()> {
+		let rendered = pretty_message_kv_to_string(
+			PrettyMessageLevel::Error,
+			"Invalid Config",
+			"CFG-004",
+			"The configuration file could not be parsed.",
+			&[("file", "alpha"), ("expected_type", "bravo"), ("ln", "charlie")],
+			None,
+			None,
+		)?;
+		let plain = strip_ansi(&rendered);
+
+		let columns: Vec<Option<usize>> = ["alpha", "bravo", "charlie"]
+			.iter()
+			.map(|value| plain.lines().find_map(|line| column_of(line, value)))
+			.collect();
+
+		assert!(columns[0].is_some());
+		assert_eq!(columns[0], columns[1]);
+		assert_eq!(columns[1], columns[2]);
+		Ok(())
+	}
+
+	#[test]
+	fn test_context_fields_render_as_bullets() -> io::Result<()> {
+		let rendered = pretty_message_kv_to_string(
+			PrettyMessageLevel::Error,
+			"Invalid Config",
+			"CFG-004",
+			"Body.",
+			&[("line", "12"), ("expected", "integer")],
+			None,
+			None,
+		)?;
+		let plain = strip_ansi(&rendered);
+
+		assert!(plain.contains("• line:     12"), "{plain}");
+		assert!(plain.contains("• expected: integer"), "{plain}");
+		let line_row = plain.find("• line:").unwrap_or(usize::MAX);
+		assert!(line_row < plain.find("• expected:").unwrap_or(0), "field order is preserved");
+		Ok(())
+	}
+
+	#[test]
+	fn test_context_fields_wrap_under_value_column() -> io::Result<()> {
+		let long_value = "word ".repeat(60);
+		let rendered = pretty_message_kv_to_string(
+			PrettyMessageLevel::Info,
+			"Wrap",
+			"WRAP-1",
+			"Body.",
+			&[("key", long_value.trim_end())],
+			None,
+			None,
+		)?;
+		let plain = strip_ansi(&rendered);
+
+		let value_rows: Vec<&str> = plain.lines().filter(|line| line.contains("word")).collect();
+		assert!(value_rows.len() > 1, "long value should wrap");
+
+		let first_col = column_of(value_rows[0], "word");
+		assert!(first_col.is_some());
+		for row in &value_rows[1..] {
+			assert_eq!(column_of(row, "word"), first_col);
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_plain_borders_keep_label_color() -> io::Result<()> {
+		let options = PrettyOptions::new()
+			.with_border_style(BorderStyle::Plain)
+			.with_color_mode(ColorMode::Always)
+			.with_icons(IconSet::symbols());
+		let rendered = render_to_string(PrettyMessageLevel::Error, &sample_parts(), options)?;
+
+		for glyph in ["│", "╭", "╮", "╰", "╯", "├", "┤", "─"] {
+			assert!(!rendered.contains(&format!("\x1b[31m{glyph}")), "{glyph} is colored");
+			assert!(!rendered.contains(&format!("\x1b[31;2m{glyph}")), "{glyph} is colored");
+		}
+		assert!(rendered.lines().nth(1).is_some_and(|line| line.starts_with('╭')));
+		assert!(rendered.contains("\x1b[31m✖"));
+		assert!(rendered.contains("31mERROR:"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_compact_sections_omit_dividers() -> io::Result<()> {
+		let options = PrettyOptions::new().with_section_style(SectionStyle::Compact);
+		let rendered = render_to_string(PrettyMessageLevel::Warning, &sample_parts(), options)?;
+		let plain = strip_ansi(&rendered);
+
+		assert!(!plain.contains('├') && !plain.contains('┤'));
+		// Only the top and bottom borders are horizontal rules
+		assert_eq!(plain.lines().filter(|line| line.contains('─')).count(), 2);
+		assert!(plain.contains("Try again."));
+
+		let dividers = strip_ansi(&render_to_string(
+			PrettyMessageLevel::Warning,
+			&sample_parts(),
+			PrettyOptions::new(),
+		)?);
+		assert_eq!(dividers.lines().filter(|line| line.contains('─')).count(), 4);
+		Ok(())
+	}
+
+	#[test]
+	fn test_tight_spacing_drops_blank_lines() -> io::Result<()> {
+		let options = PrettyOptions::new().with_spacing(Spacing::Tight);
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Info, &sample_parts(), options)?);
+
+		assert!(plain.starts_with('╭'), "{plain:?}");
+		assert!(plain.ends_with("╯\n"), "{plain:?}");
+
+		let comfortable = strip_ansi(&render_to_string(
+			PrettyMessageLevel::Info,
+			&sample_parts(),
+			PrettyOptions::new(),
+		)?);
+		assert!(comfortable.starts_with("\n╭"));
+		assert!(comfortable.ends_with("╯\n\n"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_command_row_has_prompt_and_code_color() -> io::Result<()> {
+		let parts = MessageParts { command: Some("export GMN_LOG_LEVEL=debug"), ..sample_parts() };
+		let options = PrettyOptions::new().with_color_mode(ColorMode::Always);
+		let rendered = render_to_string(PrettyMessageLevel::Warning, &parts, options)?;
+
+		let command_row = rendered.lines().find(|line| line.contains("GMN_LOG_LEVEL"));
+		assert!(command_row.is_some_and(|row| strip_ansi(row).contains("     $ export GMN_LOG")));
+		assert!(command_row.is_some_and(|row| row.contains("\x1b[96mexport GMN_LOG_LEVEL=debug")));
+
+		// The command sits below the hint text
+		let plain = strip_ansi(&rendered);
+		assert!(plain.find("Try again.") < plain.find("$ export"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_visible_len_measures_grapheme_clusters() {
+		assert_eq!(visible_len("👍🏽"), 2);
+		assert_eq!(visible_len("e\u{301}"), 1);
+		assert_eq!(visible_len("caf\u{65}\u{301} 👍🏽"), 7);
+		assert_eq!(visible_len("\x1b[31me\u{301}\x1b[0m"), 1);
+		assert_eq!(visible_len("👨\u{200d}👩\u{200d}👧"), 2);
+		assert_eq!(visible_len("🇫🇷"), 2);
+	}
+
+	#[test]
+	fn test_zwj_and_flag_emoji_keep_right_border_aligned() -> io::Result<()> {
+		let parts = MessageParts {
+			context: Some("family: 👨\u{200d}👩\u{200d}👧 here"),
+			hint: Some("flag: 🇫🇷 there"),
+			..sample_parts()
+		};
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Info, &parts, PrettyOptions::new())?);
+		let widths: Vec<usize> =
+			plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+
+		assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}\n{plain}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_wrapping_keeps_clusters_intact() {
+		let opts = WrapOptions::builder().word_wrap(true).hard_wrap(false).build();
+
+		for (cluster, width) in [("👍🏽", 2), ("e\u{301}", 1)] {
+			let long_word = cluster.repeat(30);
+			let lines = wrap_text(&format!("\x1b[33m{long_word}\x1b[0m"), 10, opts);
+
+			assert!(lines.len() > 1);
+			for line in &lines {
+				let plain = strip_ansi(line);
+				assert!(visible_len(&plain) <= 10);
+				assert!(plain.graphemes(true).all(|g| g == cluster));
+				assert_eq!(visible_len(&plain) % width, 0);
+			}
+			let rejoined: String = lines.iter().map(|line| strip_ansi(line)).collect();
+			assert_eq!(rejoined, long_word);
+		}
+	}
+
+	#[test]
+	fn test_frame_rows_align_with_clusters() -> io::Result<()> {
+		let parts = MessageParts {
+			message: "Reaction 👍🏽 received for résumé (e\u{301})",
+			context: Some(&"👍🏽".repeat(80)),
+			..sample_parts()
+		};
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Info, &parts, PrettyOptions::new())?);
+
+		let widths: Vec<usize> =
+			plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+		assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_truncate_chars_respects_char_boundaries() {
+		// "ñ" is 2 bytes and "日" is 3, so byte index 5 falls inside "日"
+		let text = "ññ日本語";
+		assert!(!text.is_char_boundary(5));
+		assert_eq!(truncate_chars(text, 3), "ññ日");
+		assert_eq!(truncate_chars(text, 0), "");
+		assert_eq!(truncate_chars(text, 10), text);
+	}
+
+	#[test]
+	fn test_truncate_display_width_respects_clusters() {
+		assert_eq!(truncate_display_width("日本語", 5), "日本");
+		assert_eq!(truncate_display_width("👍🏽👍🏽", 3), "👍🏽");
+		assert_eq!(truncate_display_width("e\u{301}e\u{301}e", 2), "e\u{301}e\u{301}");
+		assert_eq!(truncate_display_width("short", 80), "short");
+	}
+
+	#[test]
+	fn test_render_with_width_matches_golden() -> io::Result<()> {
+		let rendered = render_with_width(
+			50,
+			PrettyMessageLevel::Error,
+			"Load Failed",
+			"LD-001",
+			"The configuration file could not be read from disk.",
+			Some("path: /etc/gmn/config.toml"),
+			Some("Check the file exists and is readable."),
+			Some("config::load"),
+		)?;
+		let mut plain = strip_ansi(&rendered);
+
+		// The header clock is the only non-deterministic part; mask it
+		let stamp = plain.find("[LD-001] ").map_or(0, |idx| idx + "[LD-001] ".len());
+		plain.replace_range(stamp..stamp + "HH:MM:SS".len(), "HH:MM:SS");
+
+		let golden = "
+╭────────────────────────────────────────────────╮
+│ ✖ ERROR: LOAD FAILED         [LD-001] HH:MM:SS │
+│   ‣at config::load                             │
+│────────────────────────────────────────────────│
+│                                                │
+│  The configuration file could not be read      │
+│  from disk.                                    │
+│                                                │
+│  CONTEXT:                                      │
+│  path: /etc/gmn/config.toml                    │
+│                                                │
+│────────────────────────────────────────────────│
+│  ➜  HINT                                       │
+│     Check the file exists and is readable.     │
+╰────────────────────────────────────────────────╯
+
+";
+		assert_eq!(plain, golden);
+		Ok(())
+	}
+
+	/// Rerun the named tests of this binary in a child process with `env` set
+	///
+	/// Tests cannot change the environment of their own process, so this is how they
+	/// check what a render does under a given `TERM` or locale.
+	fn run_tests_with_env(tests: &[&str], env: &[(&str, &str)]) -> io::Result<()> {
+		let output = std::process::Command::new(std::env::current_exe()?)
+			.args(["--exact", "--include-ignored"])
+			.args(tests.iter().map(|test| format!("print_pretty_error::tests::{test}")))
+			.env_remove("LC_ALL")
+			.env_remove("LC_CTYPE")
+			.envs(env.iter().copied())
+			.output()?;
+		let stdout = String::from_utf8_lossy(&output.stdout);
+
+		assert!(output.status.success(), "{stdout}");
+		assert!(stdout.contains(&format!("{} passed", tests.len())), "{stdout}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_snapshot_renders_ignore_term_and_locale() -> io::Result<()> {
+		run_tests_with_env(
+			&["test_render_with_width_matches_golden", "test_fixed_width_keeps_configured_glyphs"],
+			&[("TERM", "dumb"), ("LANG", "C")],
+		)
+	}
+
+	#[test]
+	fn test_print_to_writer_captures_frame() -> io::Result<()> {
+		let mut out = Vec::new();
+		print_pretty_warning_to(
+			&mut out,
+			"Disk Almost Full",
+			"DSK-090",
+			"Only 2 GB left on /var.",
+			None,
+			Some("Prune old logs."),
+			None,
+		)?;
+		let plain = strip_ansi(&String::from_utf8_lossy(&out));
+
+		assert!(plain.starts_with("\n╭"));
+		assert!(plain.contains("WARNING: DISK ALMOST FULL"));
+		assert!(plain.contains("[DSK-090]"));
+		assert!(plain.contains("Prune old logs."));
+
+		let mut dyn_out: Vec<u8> = Vec::new();
+		let writer: &mut dyn Write = &mut dyn_out;
+		print_pretty_error_to(writer, "Boom", "ERR-1", "Failed.", None, None, None)?;
+		assert!(strip_ansi(&String::from_utf8_lossy(&dyn_out)).contains("ERROR: BOOM"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_try_print_reports_success() {
+		let result = try_print_pretty_message(
+			PrettyMessageLevel::Info,
+			"Checked",
+			"CHK-001",
+			"Written to stderr.",
+			None,
+			None,
+			None,
+		);
+		assert!(result.is_ok(), "{result:?}");
+	}
+
+	#[test]
+	fn test_ansi_enabled_follows_env_and_tty() {
+		let auto = ColorMode::Auto;
+		assert!(ansi_enabled(auto, None, None, true));
+		assert!(!ansi_enabled(auto, None, None, false));
+		assert!(!ansi_enabled(auto, None, Some("1"), true));
+		// An empty NO_COLOR does not count
+		assert!(ansi_enabled(auto, None, Some(""), true));
+		assert!(ansi_enabled(auto, Some("1"), None, false));
+		assert!(ansi_enabled(auto, Some("1"), Some("1"), false));
+		assert!(!ansi_enabled(auto, Some("0"), None, false));
+
+		assert!(ansi_enabled(ColorMode::Always, None, Some("1"), false));
+		assert!(!ansi_enabled(ColorMode::Never, Some("1"), None, true));
+	}
+
+	#[test]
+	fn test_color_never_emits_no_escapes() -> io::Result<()> {
+		let parts = MessageParts {
+			message: "Already \x1b[35mstyled\x1b[0m text.",
+			command: Some("cargo build"),
+			..sample_parts()
+		};
+		let options =
+			PrettyOptions::new().with_color_mode(ColorMode::Never).with_icons(IconSet::symbols());
+		let rendered = render_to_string(PrettyMessageLevel::Error, &parts, options)?;
+
+		assert!(!rendered.contains('\x1b'), "{rendered:?}");
+		assert!(rendered.contains("│ ✖ ERROR: SAMPLE"));
+		assert!(rendered.contains("Already styled text."));
+
+		let colored = render_to_string(
+			PrettyMessageLevel::Error,
+			&parts,
+			options.with_color_mode(ColorMode::Always),
+		)?;
+		assert!(colored.contains("\x1b[31m│"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_forced_color_styles_without_touching_colored() -> io::Result<()> {
+		let before = colored::control::SHOULD_COLORIZE.should_colorize();
+		let options =
+			PrettyOptions::new().with_color_mode(ColorMode::Always).with_icons(IconSet::symbols());
+		let rendered = render_to_string(PrettyMessageLevel::Error, &sample_parts(), options)?;
+
+		// Styled even when `colored` would not style anything, e.g. with stdout piped
+		assert!(rendered.contains("\x1b[31m✖"), "{rendered:?}");
+		assert!(rendered.contains("\x1b[1mSAMPLE"), "{rendered:?}");
+		assert_eq!(colored::control::SHOULD_COLORIZE.should_colorize(), before);
+		Ok(())
+	}
+
+	#[test]
+	fn test_ascii_icons_in_header() -> io::Result<()> {
+		let options = PrettyOptions::new().with_icons(IconSet::ascii());
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Error, &sample_parts(), options)?);
+
+		assert!(plain.contains("│ X ERROR: SAMPLE"), "{plain}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_default_icons_fall_back_in_non_utf8_locale() {
+		let symbols = IconSet::symbols();
+		assert_eq!(symbols.for_locale(Some("en_US.UTF-8")), symbols);
+		assert_eq!(symbols.for_locale(Some("C.utf8")), symbols);
+		assert_eq!(symbols.for_locale(None), symbols);
+		assert_eq!(symbols.for_locale(Some("C")), IconSet::bracketed());
+		assert_eq!(symbols.for_locale(Some("de_DE.ISO-8859-1")), IconSet::bracketed());
+		// An explicitly chosen set is never replaced
+		assert_eq!(IconSet::emoji().for_locale(Some("C")), IconSet::emoji());
+	}
+
+	#[test]
+	fn test_bracketed_icons_keep_rows_aligned() -> io::Result<()> {
+		let options = PrettyOptions::new().with_icons(IconSet::bracketed());
+
+		for level in [PrettyMessageLevel::Success, PrettyMessageLevel::Input] {
+			let plain = strip_ansi(&render_to_string(level, &sample_parts(), options)?);
+			let widths: Vec<usize> =
+				plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+			assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}");
+			assert!(plain.contains("│ ["), "{plain}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_emoji_icons_keep_rows_aligned() -> io::Result<()> {
+		let options = PrettyOptions::new().with_icons(IconSet::emoji());
+
+		for level in
+			[PrettyMessageLevel::Warning, PrettyMessageLevel::Info, PrettyMessageLevel::Input]
+		{
+			let plain = strip_ansi(&render_to_string(level, &sample_parts(), options)?);
+			let widths: Vec<usize> =
+				plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+			assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_location_url_needs_line_number() -> io::Result<()> {
+		let cwd = std::env::current_dir()?;
+		let expected = format!("file://{}#L42", cwd.join("src/foo.rs").display());
+
+		assert_eq!(location_url("src/foo.rs:42"), Some(expected.clone()));
+		assert_eq!(location_url("src/foo.rs:42:3"), Some(expected));
+		assert_eq!(location_url("/abs/lib.rs:7").as_deref(), Some("file:///abs/lib.rs#L7"));
+		assert_eq!(location_url("auth::middleware::verify_token"), None);
+		assert_eq!(location_url("main"), None);
+		Ok(())
+	}
+
+	#[test]
+	fn test_hyperlinked_location_keeps_width() -> io::Result<()> {
+		let parts = MessageParts { location: Some("src/foo.rs:42:3"), ..sample_parts() };
+		let options = PrettyOptions::new().with_color_mode(ColorMode::Always);
+
+		let linked =
+			render_to_string(PrettyMessageLevel::Error, &parts, options.with_hyperlinks(true))?;
+		let location_row = linked.lines().find(|line| line.contains("src/foo.rs"));
+		assert!(location_row.is_some_and(|row| row.contains("\x1b]8;;file://")));
+		assert!(location_row.is_some_and(|row| row.contains("\x1b]8;;\x1b\\")));
+
+		let plain = render_to_string(PrettyMessageLevel::Error, &parts, options)?;
+		assert!(!plain.contains("\x1b]8;;"));
+
+		let widths: Vec<usize> =
+			linked.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+		assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_box_styles_change_glyphs_only() -> io::Result<()> {
+		let options = PrettyOptions::new().with_color_mode(ColorMode::Always);
+
+		let ascii = render_to_string(
+			PrettyMessageLevel::Error,
+			&sample_parts(),
+			options.with_box_style(BoxStyle::Ascii),
+		)?;
+		let plain = strip_ansi(&ascii);
+		assert!(
+			plain
+				.lines()
+				.nth(1)
+				.is_some_and(|line| line.starts_with("+---") && line.ends_with("-+"))
+		);
+		assert!(
+			plain.lines().nth(2).is_some_and(|line| line.starts_with("| ") && line.ends_with(" |"))
+		);
+		assert!(!plain.chars().any(|c| "╭╮╰╯│─├┤".contains(c)));
+		// Border color is kept per level
+		assert!(ascii.contains("\x1b[31m|"));
+
+		let square = strip_ansi(&render_to_string(
+			PrettyMessageLevel::Info,
+			&sample_parts(),
+			options.with_box_style(BoxStyle::Square),
+		)?);
+		assert!(square.contains('┌') && square.contains('┘'));
+		assert!(!square.contains('╭'));
+		Ok(())
+	}
+
+	#[test]
+	fn test_auto_box_style_falls_back_to_ascii_on_dumb_term() {
+		assert_eq!(BoxStyle::Auto.for_term(Some("dumb")).glyphs().top_left, "+");
+		assert_eq!(BoxStyle::Auto.for_term(Some("xterm-256color")).glyphs().top_left, "╭");
+		assert_eq!(BoxStyle::Auto.for_term(None).glyphs().top_left, "╭");
+		assert_eq!(BoxStyle::Rounded.for_term(Some("dumb")).glyphs().top_left, "╭");
+		assert_eq!(BoxStyle::Auto.glyphs().top_left, "╭");
+	}
+
+	#[test]
+	fn test_timestamp_can_be_disabled_or_reformatted() -> io::Result<()> {
+		let hidden = strip_ansi(&render_to_string(
+			PrettyMessageLevel::Info,
+			&sample_parts(),
+			PrettyOptions::new().with_timestamp(TimestampConfig::None),
+		)?);
+		let header = hidden.lines().nth(2).unwrap_or_default();
+		assert!(header.ends_with("[SMP-001] │"), "{header}");
+
+		let year = Utc::now().format("%Y").to_string();
+		let utc = strip_ansi(&render_to_string(
+			PrettyMessageLevel::Info,
+			&sample_parts(),
+			PrettyOptions::new().with_timestamp(TimestampConfig::Utc("%Y UTC")),
+		)?);
+		assert!(utc.contains(&format!("[SMP-001] {year} UTC │")), "{utc}");
+
+		for plain in [&hidden, &utc] {
+			let widths: Vec<usize> =
+				plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+			assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_invalid_timestamp_format_is_hidden() {
+		assert_eq!(format_timestamp(TimestampConfig::Local("%Q")), None);
+		assert!(format_timestamp(TimestampConfig::Utc("%H:%M")).is_some());
+	}
+
+	#[test]
+	fn test_measured_and_drawn_timestamp_match() -> io::Result<()> {
+		let frame =
+			frame_for(PrettyMessageLevel::Info, PrettyOptions::new(), false, ROUNDED_GLYPHS);
+		let short = MessageParts { timestamp: Some("1"), ..sample_parts() };
+		let long = MessageParts { timestamp: Some("Saturday 31 December 1999, 23:59:59"), ..short };
+
+		// The stamp carried in the parts is the one measured
+		assert!(
+			compute_content_width(&frame, &long, None)
+				> compute_content_width(&frame, &short, None)
+		);
+
+		let width = compute_content_width(&frame, &long, None);
+		let mut out = Vec::new();
+		render_pretty_message(&mut out, &frame, width, &long, PrettyOptions::new())?;
+		let plain = strip_ansi(&String::from_utf8_lossy(&out));
+		let header = plain.lines().nth(2).unwrap_or_default();
+
+		assert!(header.ends_with("[SMP-001] Saturday 31 December 1999, 23:59:59 │"), "{header}");
+		assert_eq!(visible_len(header), width + 2);
+		Ok(())
+	}
+
+	#[test]
+	fn test_causes_render_inside_frame() -> io::Result<()> {
+		let causes = vec![
+			"Configuration error: invalid output path".to_string(),
+			"Permission denied (os error 13) while opening /var/log/app/gmn.log for writing"
+				.to_string(),
+		];
+		let parts = MessageParts { causes: &causes, ..sample_parts() };
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Error, &parts, PrettyOptions::new())?);
+
+		let rows: Vec<&str> = plain.lines().collect();
+		let first = rows.iter().position(|row| row.contains("caused by: Configuration"));
+		let second = rows.iter().position(|row| row.contains("caused by: Permission"));
+		assert!(first.is_some() && first < second, "{plain}");
+		assert_eq!(first.and_then(|i| column_of(rows[i], "caused")), Some(3));
+		assert_eq!(second.and_then(|i| column_of(rows[i], "caused")), Some(5));
+
+		let widths: Vec<usize> =
+			plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+		assert!(widths.windows(2).all(|pair| pair[0] == pair[1]), "{widths:?}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_long_context_is_truncated_with_marker() -> io::Result<()> {
+		let mut blob: Vec<String> = (1..=30).map(|i| format!("line {i}")).collect();
+		// Past the cap: must neither show up nor widen the frame
+		blob.push("x".repeat(120));
+		let blob = blob.join("\n");
+		let parts = MessageParts { context: Some(&blob), ..sample_parts() };
+
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Info, &parts, PrettyOptions::new())?);
+		assert!(plain.contains("line 20"));
+		assert!(!plain.contains("line 21"));
+		// 10 source lines plus the wrapped pieces of the long one
+		assert!(plain.contains("more lines)"), "{plain}");
+		assert!(!plain.contains("xxxx"));
+		assert!(plain.lines().all(|line| visible_len(line) < 100));
+
+		let short = MessageParts { context: Some("line 1\nline 2"), ..sample_parts() };
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Info, &short, PrettyOptions::new())?);
+		assert!(!plain.contains("more lines"));
+
+		let all = strip_ansi(&render_to_string(
+			PrettyMessageLevel::Info,
+			&parts,
+			PrettyOptions::new().with_max_context_lines(None),
+		)?);
+		assert!(all.contains("line 30") && !all.contains("more lines"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_dropped_wrapped_context_lines_do_not_widen_the_frame() -> io::Result<()> {
+		let frame =
+			frame_for(PrettyMessageLevel::Info, PrettyOptions::new(), false, ROUNDED_GLYPHS);
+		// One source line wrapping into more rows than are kept, then a wider one
+		let wrapping = "word ".repeat(60);
+		let kept = MessageParts { context: Some(&wrapping), ..sample_parts() };
+		let blob = format!("{wrapping}\n{}", "x".repeat(400));
+		let parts = MessageParts { context: Some(&blob), ..sample_parts() };
+
+		let width = compute_content_width(&frame, &parts, Some(2));
+		assert_eq!(width, compute_content_width(&frame, &kept, Some(2)));
+
+		let options = PrettyOptions::new().with_max_context_lines(Some(2));
+		let mut out = Vec::new();
+		render_pretty_message(&mut out, &frame, width, &parts, options)?;
+		let plain = strip_ansi(&String::from_utf8_lossy(&out));
+		assert!(!plain.contains("xxxx"), "{plain}");
+		assert!(plain.contains("more lines)"), "{plain}");
+		let widths: Vec<usize> =
+			plain.lines().filter(|line| !line.is_empty()).map(visible_len).collect();
+		assert!(widths.iter().all(|&row| row == width + 2), "{widths:?}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_message_json_is_one_line_with_expected_fields() -> Result<(), serde_json::Error> {
+		let context = format!("{} \"quoted\"\nsecond line", "styled".red());
+		let parts = MessageParts {
+			title: "Database Error",
+			code: "GMN_CORE_000",
+			message: "Connection refused.",
+			context: Some(&context),
+			..MessageParts::default()
+		};
+		let line = message_json(PrettyMessageLevel::Error, &parts, "2026-01-02T03:04:05.000Z");
+		assert!(!line.contains('\n'));
+
+		let value: serde_json::Value = serde_json::from_str(&line)?;
+		assert_eq!(value["level"], "error");
+		assert_eq!(value["code"], "GMN_CORE_000");
+		assert_eq!(value["timestamp"], "2026-01-02T03:04:05.000Z");
+		assert_eq!(value["context"], "styled \"quoted\"\nsecond line");
+		assert!(value.get("hint").is_none());
+		Ok(())
+	}
+
+	#[test]
+	fn test_json_timestamp_is_rfc3339() -> Result<(), chrono::ParseError> {
+		let line =
+			pretty_message_to_json(PrettyMessageLevel::Info, "T", "C", "M", None, None, None);
+		let stamp = line
+			.split(r#""timestamp":""#)
+			.nth(1)
+			.and_then(|rest| rest.split('"').next())
+			.unwrap_or_default();
+		chrono::DateTime::parse_from_rfc3339(stamp)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_fixed_width_option_ignores_content_size() -> io::Result<()> {
+		for (fixed, expected) in [(72, 72), (3, MIN_FIXED_WIDTH)] {
+			let options = PrettyOptions::new().with_fixed_width(Some(fixed));
+			let plain =
+				strip_ansi(&render_to_string(PrettyMessageLevel::Info, &sample_parts(), options)?);
+			let rows = plain.lines().filter(|line| !line.is_empty());
+			assert!(rows.into_iter().all(|line| visible_len(line) == expected), "{plain}");
+		}
+		Ok(())
+	}
+
+	#[test]
+	fn test_fixed_width_keeps_configured_glyphs() -> io::Result<()> {
+		let options =
+			PrettyOptions::new().with_fixed_width(Some(60)).with_icons(IconSet::symbols());
+		let plain =
+			strip_ansi(&render_to_string(PrettyMessageLevel::Error, &sample_parts(), options)?);
+
+		assert!(plain.lines().nth(1).is_some_and(|line| line.starts_with('╭')), "{plain}");
+		assert!(plain.contains("│ ✖ ERROR: SAMPLE"), "{plain}");
+		Ok(())
+	}
+}
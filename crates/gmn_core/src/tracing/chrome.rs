@@ -0,0 +1,296 @@
+//! Chrome trace-event output for local profiling.
+//!
+//! [`ChromeTraceLayer`] writes spans and events to a file in the trace-event JSON
+//! array format, which `chrome://tracing` and [Perfetto](https://ui.perfetto.dev) open
+//! directly. Every closed span becomes a complete event (`"ph": "X"`) with its start
+//! time and duration, and every event becomes a thread-scoped instant (`"ph": "i"`).
+//! Recorded fields are kept in `args`.
+//!
+//! The array is only closed when the [`ChromeTraceGuard`] returned alongside the layer
+//! is dropped or [finished](ChromeTraceGuard::finish), so hold on to it until the
+//! traced work is done.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Layer writing spans and events as Chrome trace events
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::tracing::chrome::ChromeTraceLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// fn main() -> std::io::Result<()> {
+///     let (layer, guard) = ChromeTraceLayer::new("trace.json")?;
+///     let subscriber = tracing_subscriber::registry().with(layer);
+///     tracing::subscriber::with_default(subscriber, || {
+///         let _span = tracing::info_span!("startup").entered();
+///     });
+///     guard.finish()
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ChromeTraceLayer {
+	writer: Arc<Mutex<TraceWriter>>,
+	origin: Instant,
+}
+
+/// Closes the trace file written by a [`ChromeTraceLayer`]
+///
+/// Dropping the guard writes the closing `]` and flushes; use
+/// [`finish`](Self::finish) instead to see I/O errors.
+#[derive(Debug)]
+pub struct ChromeTraceGuard {
+	writer: Arc<Mutex<TraceWriter>>,
+}
+
+impl ChromeTraceLayer {
+	/// Create (or truncate) the trace file at `path` and return the layer with its guard
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file cannot be created or written.
+	pub fn new(path: impl AsRef<Path>) -> io::Result<(Self, ChromeTraceGuard)> {
+		let mut out = BufWriter::new(File::create(path)?);
+		out.write_all(b"[\n")?;
+
+		let writer = Arc::new(Mutex::new(TraceWriter { out, empty: true, closed: false }));
+		let guard = ChromeTraceGuard { writer: Arc::clone(&writer) };
+		Ok((Self { writer, origin: Instant::now() }, guard))
+	}
+
+	/// Microseconds between the layer's creation and `instant`
+	fn micros_since_origin(&self, instant: Instant) -> f64 {
+		instant.duration_since(self.origin).as_secs_f64() * 1_000_000.0
+	}
+
+	fn write(&self, event: &TraceEvent<'_>) {
+		// A failing trace file must never break the traced program
+		let _ = self.writer.lock().unwrap_or_else(PoisonError::into_inner).write_event(event);
+	}
+}
+
+impl ChromeTraceGuard {
+	/// Flush buffered events without closing the array
+	///
+	/// # Errors
+	///
+	/// Returns an error if writing to the trace file fails.
+	pub fn flush(&self) -> io::Result<()> {
+		self.writer.lock().unwrap_or_else(PoisonError::into_inner).out.flush()
+	}
+
+	/// Close the array and flush; later spans and events are discarded
+	///
+	/// # Errors
+	///
+	/// Returns an error if writing to the trace file fails.
+	pub fn finish(self) -> io::Result<()> {
+		self.writer.lock().unwrap_or_else(PoisonError::into_inner).close()
+	}
+}
+
+impl Drop for ChromeTraceGuard {
+	fn drop(&mut self) {
+		let _ = self.writer.lock().unwrap_or_else(PoisonError::into_inner).close();
+	}
+}
+
+/// The trace file and whether an event has been written to it yet
+#[derive(Debug)]
+struct TraceWriter {
+	out: BufWriter<File>,
+	empty: bool,
+	closed: bool,
+}
+
+impl TraceWriter {
+	fn write_event(&mut self, event: &TraceEvent<'_>) -> io::Result<()> {
+		if self.closed {
+			return Ok(());
+		}
+		if !self.empty {
+			self.out.write_all(b",\n")?;
+		}
+		self.empty = false;
+		serde_json::to_writer(&mut self.out, event).map_err(io::Error::from)
+	}
+
+	fn close(&mut self) -> io::Result<()> {
+		if !self.closed {
+			self.closed = true;
+			self.out.write_all(b"\n]\n")?;
+		}
+		self.out.flush()
+	}
+}
+
+/// One entry of the trace-event array
+#[derive(Serialize)]
+struct TraceEvent<'a> {
+	name: &'a str,
+	cat: &'a str,
+	ph: &'static str,
+	ts: f64,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	dur: Option<f64>,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	s: Option<&'static str>,
+	pid: u32,
+	tid: u64,
+	args: &'a Map<String, Value>,
+}
+
+/// Start time, thread and fields of an open span, stored in its extensions
+struct SpanTiming {
+	started: Instant,
+	tid: u64,
+	args: Map<String, Value>,
+}
+
+/// Visitor collecting fields as JSON values, keeping numbers and booleans typed
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+	fn record_i64(&mut self, field: &Field, value: i64) {
+		self.0.insert(field.name().to_string(), value.into());
+	}
+
+	fn record_u64(&mut self, field: &Field, value: u64) {
+		self.0.insert(field.name().to_string(), value.into());
+	}
+
+	fn record_f64(&mut self, field: &Field, value: f64) {
+		self.0.insert(field.name().to_string(), value.into());
+	}
+
+	fn record_bool(&mut self, field: &Field, value: bool) {
+		self.0.insert(field.name().to_string(), value.into());
+	}
+
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.0.insert(field.name().to_string(), value.into());
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+		self.0.insert(field.name().to_string(), format!("{value:?}").into());
+	}
+}
+
+/// Small, stable per-thread id; trace viewers group events into one row per `tid`
+fn current_tid() -> u64 {
+	static NEXT_TID: AtomicU64 = AtomicU64::new(1);
+	thread_local! {
+		static TID: u64 = NEXT_TID.fetch_add(1, Ordering::Relaxed);
+	}
+	TID.with(|tid| *tid)
+}
+
+impl<S> Layer<S> for ChromeTraceLayer
+where
+	S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+	fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id) {
+			let mut args = Map::new();
+			attrs.record(&mut JsonVisitor(&mut args));
+			span.extensions_mut().insert(SpanTiming {
+				started: Instant::now(),
+				tid: current_tid(),
+				args,
+			});
+		}
+	}
+
+	fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id)
+			&& let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>()
+		{
+			values.record(&mut JsonVisitor(&mut timing.args));
+		}
+	}
+
+	fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+		let mut args = Map::new();
+		event.record(&mut JsonVisitor(&mut args));
+		let metadata = event.metadata();
+		let name = args.get("message").and_then(Value::as_str).unwrap_or_else(|| metadata.name());
+
+		self.write(&TraceEvent {
+			name,
+			cat: metadata.target(),
+			ph: "i",
+			ts: self.micros_since_origin(Instant::now()),
+			dur: None,
+			s: Some("t"),
+			pid: std::process::id(),
+			tid: current_tid(),
+			args: &args,
+		});
+	}
+
+	fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+		let Some(span) = ctx.span(&id) else { return };
+		let Some(timing) = span.extensions_mut().remove::<SpanTiming>() else { return };
+
+		self.write(&TraceEvent {
+			name: span.name(),
+			cat: span.metadata().target(),
+			ph: "X",
+			ts: self.micros_since_origin(timing.started),
+			dur: Some(timing.started.elapsed().as_secs_f64() * 1_000_000.0),
+			s: None,
+			pid: std::process::id(),
+			tid: timing.tid,
+			args: &timing.args,
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tracing_subscriber::layer::SubscriberExt;
+
+	#[test]
+	fn test_spans_are_written_as_complete_events() -> Result<(), Box<dyn std::error::Error>> {
+		let path = std::env::temp_dir().join(format!("gmn-chrome-{}.json", uuid::Uuid::new_v4()));
+		let (layer, guard) = ChromeTraceLayer::new(&path)?;
+
+		let subscriber = tracing_subscriber::registry().with(layer);
+		tracing::subscriber::with_default(subscriber, || {
+			let outer = tracing::info_span!("load_config", attempt = 1_u64).entered();
+			{
+				let _inner = tracing::info_span!("parse").entered();
+				tracing::info!("parsed");
+			}
+			drop(outer);
+		});
+		guard.finish()?;
+
+		let contents = std::fs::read_to_string(&path);
+		std::fs::remove_file(&path)?;
+		let events: Vec<Value> = serde_json::from_str(&contents?)?;
+
+		let complete: Vec<&Value> = events.iter().filter(|event| event["ph"] == "X").collect();
+		let names: Vec<&str> = complete.iter().filter_map(|event| event["name"].as_str()).collect();
+		assert_eq!(names, ["parse", "load_config"]);
+		assert!(complete.iter().all(|event| event["dur"].as_f64().is_some_and(|dur| dur >= 0.0)));
+		assert_eq!(complete[1]["args"]["attempt"], 1);
+
+		assert!(events.iter().any(|event| event["ph"] == "i" && event["name"] == "parsed"));
+		Ok(())
+	}
+}
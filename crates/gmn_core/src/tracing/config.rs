@@ -3,11 +3,16 @@
 //! This module provides configuration options for the tracing infrastructure,
 //! including log levels, output formats, and environment variable support.
 
+use super::setup::build_env_filter;
+use crate::error_display::display_warning;
+use crate::errors::{ConfigError, GmnError, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use tracing::Level;
 
 /// Output format for tracing logs
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum LogFormat {
 	/// Pretty-printed format with colors (best for development)
 	Pretty,
@@ -36,7 +41,12 @@ impl LogFormat {
 }
 
 /// Output target for tracing logs
+///
+/// In a config file this is `"stdout"`, `"stderr"`, `{ file = "logs/app.log" }`,
+/// `{ both = { console = "stderr", file = "logs/app.log" } }`, `"journald"` or
+/// `{ syslog = { facility = "daemon" } }`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum LogOutput {
 	/// Write to stdout
 	Stdout,
@@ -51,6 +61,34 @@ pub enum LogOutput {
 		/// File path for persistent logs
 		file: PathBuf,
 	},
+	/// Send to the systemd journal (requires the `journald` feature)
+	///
+	/// The journal stores event fields natively, so `format` is ignored.
+	Journald,
+	/// Send to the local syslog daemon (requires the `syslog` feature)
+	Syslog {
+		/// Syslog facility name, e.g. `"daemon"` or `"local0"` (default: `"user"`)
+		#[serde(default = "default_syslog_facility")]
+		facility: String,
+	},
+}
+
+/// Read an environment variable, treating non-UTF-8 values as unset
+fn env_var(name: &str) -> Option<String> {
+	std::env::var(name).ok()
+}
+
+/// Parse a boolean environment value, accepting the usual spellings
+fn parse_flag(value: &str) -> Option<bool> {
+	match value.trim().to_lowercase().as_str() {
+		"true" | "1" | "yes" | "on" => Some(true),
+		"false" | "0" | "no" | "off" => Some(false),
+		_ => None,
+	}
+}
+
+fn default_syslog_facility() -> String {
+	"user".to_string()
 }
 
 impl Default for LogOutput {
@@ -59,8 +97,53 @@ impl Default for LogOutput {
 	}
 }
 
+/// How often the log file is rotated
+///
+/// Rotated files get a date suffix (`app.log.2024-05-01-13` for hourly rotation);
+/// with [`Never`](Self::Never) everything goes to the configured file itself.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rotation {
+	/// Start a new file every minute
+	Minutely,
+	/// Start a new file every hour
+	Hourly,
+	/// Start a new file every day
+	#[default]
+	Daily,
+	/// Keep writing to a single file
+	Never,
+}
+
+impl Rotation {
+	/// Parse a rotation policy from its name (case-insensitive)
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name.to_lowercase().as_str() {
+			"minutely" => Some(Self::Minutely),
+			"hourly" => Some(Self::Hourly),
+			"daily" => Some(Self::Daily),
+			"never" => Some(Self::Never),
+			_ => None,
+		}
+	}
+}
+
+impl From<Rotation> for tracing_appender::rolling::Rotation {
+	fn from(rotation: Rotation) -> Self {
+		match rotation {
+			Rotation::Minutely => Self::MINUTELY,
+			Rotation::Hourly => Self::HOURLY,
+			Rotation::Daily => Self::DAILY,
+			Rotation::Never => Self::NEVER,
+		}
+	}
+}
+
 /// Configuration for tracing and logging
+///
+/// When deserialized, missing fields take their [`Default`] values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TracingConfig {
 	/// Log level filter (e.g., "debug", "info", "warn", "error")
 	/// Can also use directive syntax like "gmn_core=debug,hyper=info"
@@ -72,9 +155,18 @@ pub struct TracingConfig {
 	/// Output target
 	pub output: LogOutput,
 
+	/// Rotation policy of the log file (ignored without file output)
+	pub rotation: Rotation,
+
 	/// Whether to include timestamps
 	pub with_timestamps: bool,
 
+	/// Timestamp layout as a [`time` format description], in UTC, e.g.
+	/// `"[hour]:[minute]:[second].[subsecond digits:3]"`; `None` keeps the RFC 3339 default
+	///
+	/// [`time` format description]: https://time-rs.github.io/book/api/format-description.html
+	pub time_format: Option<String>,
+
 	/// Whether to include thread IDs
 	pub with_thread_ids: bool,
 
@@ -84,11 +176,94 @@ pub struct TracingConfig {
 	/// Whether to include file/line information
 	pub with_file_line: bool,
 
-	/// Whether to include span information
+	/// Whether to include the list of entered spans (only applies to Json format)
 	pub with_span_list: bool,
 
-	/// Whether to use ANSI colors (only applies to Pretty format)
+	/// Whether to use ANSI colors on the console (log files never get them)
 	pub with_ansi: bool,
+
+	/// Whether to forward `log` crate records into the subscriber
+	/// (requires the opt-in `log-compat` feature; a no-op without it)
+	pub capture_log: bool,
+
+	/// File to write spans to in Chrome trace-event format, if any
+	/// (see [`ChromeTraceLayer`](super::chrome::ChromeTraceLayer))
+	pub chrome_trace: Option<PathBuf>,
+
+	/// OTLP/HTTP traces endpoint to export spans to, if any
+	/// (requires the `otel` feature, see [`otel`](super::otel))
+	pub otlp_endpoint: Option<String>,
+
+	/// Per-target levels added on top of `log_level`
+	/// (see [`with_target_level`](Self::with_target_level))
+	#[serde(with = "target_levels_serde")]
+	pub target_levels: Vec<(String, Level)>,
+}
+
+/// Target levels as a `target = "level"` table, since [`Level`] has no serde support
+mod target_levels_serde {
+	use serde::de::Error as _;
+	use serde::{Deserialize, Deserializer, Serializer};
+	use std::collections::BTreeMap;
+	use tracing::Level;
+
+	pub fn serialize<S: Serializer>(
+		levels: &[(String, Level)],
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.collect_map(levels.iter().map(|(target, level)| (target, level.as_str())))
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Vec<(String, Level)>, D::Error> {
+		BTreeMap::<String, String>::deserialize(deserializer)?
+			.into_iter()
+			.map(|(target, level)| Ok((target, level.parse().map_err(D::Error::custom)?)))
+			.collect()
+	}
+}
+
+/// The configuration `init_tracing_with_config` actually applies, flattened for logging
+///
+/// Produced by [`TracingConfig::resolved`]. `RUST_LOG` directives are already merged into
+/// `log_filter`, and [`LogOutput`] is split into its console and file parts.
+// The flags mirror `TracingConfig`'s toggles one-to-one so the serialized form reads the same
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolvedTracingConfig {
+	/// Effective level filter, `log_level` with `RUST_LOG` layered on top
+	pub log_filter: String,
+	/// Output format
+	pub format: LogFormat,
+	/// Console stream (`"stdout"` or `"stderr"`), if logging to the console
+	pub console: Option<&'static str>,
+	/// Log file, if logging to a file
+	pub file: Option<PathBuf>,
+	/// System logger (`"journald"` or `"syslog (<facility>)"`), if logging to one
+	pub system_log: Option<String>,
+	/// Rotation policy of the log file, if logging to a file
+	pub rotation: Option<Rotation>,
+	/// Whether timestamps are included
+	pub with_timestamps: bool,
+	/// Custom timestamp layout, if any
+	pub time_format: Option<String>,
+	/// Whether thread IDs are included
+	pub with_thread_ids: bool,
+	/// Whether thread names are included
+	pub with_thread_names: bool,
+	/// Whether file/line information is included
+	pub with_file_line: bool,
+	/// Whether span information is included
+	pub with_span_list: bool,
+	/// Whether ANSI colors are used
+	pub with_ansi: bool,
+	/// Whether `log` crate records are forwarded
+	pub capture_log: bool,
+	/// Chrome trace-event output file, if any
+	pub chrome_trace: Option<PathBuf>,
+	/// OTLP endpoint spans are exported to, if any
+	pub otlp_endpoint: Option<String>,
 }
 
 impl Default for TracingConfig {
@@ -97,12 +272,18 @@ impl Default for TracingConfig {
 			log_level: "info".to_string(),
 			format: LogFormat::default(),
 			output: LogOutput::default(),
+			rotation: Rotation::Daily,
 			with_timestamps: true,
+			time_format: None,
 			with_thread_ids: false,
 			with_thread_names: false,
 			with_file_line: true,
 			with_span_list: true,
 			with_ansi: true,
+			capture_log: true,
+			chrome_trace: None,
+			otlp_endpoint: None,
+			target_levels: Vec::new(),
 		}
 	}
 }
@@ -113,64 +294,236 @@ impl TracingConfig {
 		Self::default()
 	}
 
+	/// Flatten this configuration into what initialization will actually apply
+	///
+	/// Reads `RUST_LOG` to compute the effective filter, exactly as
+	/// [`init_tracing_with_config`](super::init_tracing_with_config) does.
+	pub fn resolved(&self) -> ResolvedTracingConfig {
+		self.resolved_with(
+			std::env::var(tracing_subscriber::EnvFilter::DEFAULT_ENV).ok().as_deref(),
+		)
+	}
+
+	fn resolved_with(&self, env_directives: Option<&str>) -> ResolvedTracingConfig {
+		let (console, file, system_log) = match &self.output {
+			LogOutput::Stdout => (Some("stdout"), None, None),
+			LogOutput::Stderr => (Some("stderr"), None, None),
+			LogOutput::File(path) => (None, Some(path.clone()), None),
+			// Any console other than stdout falls back to stderr, as in setup
+			LogOutput::Both { console, file } => {
+				let stream = if **console == LogOutput::Stdout { "stdout" } else { "stderr" };
+				(Some(stream), Some(file.clone()), None)
+			}
+			LogOutput::Journald => (None, None, Some("journald".to_string())),
+			LogOutput::Syslog { facility } => (None, None, Some(format!("syslog ({facility})"))),
+		};
+
+		ResolvedTracingConfig {
+			log_filter: build_env_filter(&self.filter_directives(), env_directives).to_string(),
+			format: self.format,
+			console,
+			rotation: file.as_ref().map(|_| self.rotation),
+			file,
+			system_log,
+			with_timestamps: self.with_timestamps,
+			time_format: self.time_format.clone(),
+			with_thread_ids: self.with_thread_ids,
+			with_thread_names: self.with_thread_names,
+			with_file_line: self.with_file_line,
+			with_span_list: self.with_span_list,
+			with_ansi: self.with_ansi,
+			capture_log: self.capture_log,
+			chrome_trace: self.chrome_trace.clone(),
+			otlp_endpoint: self.otlp_endpoint.clone(),
+		}
+	}
+
+	/// `log_level` followed by the [per-target levels](Self::with_target_level), as one
+	/// filter string
+	pub fn filter_directives(&self) -> String {
+		let targets = self
+			.target_levels
+			.iter()
+			.map(|(target, level)| format!("{target}={}", level.as_str().to_lowercase()));
+		std::iter::once(self.log_level.clone())
+			.filter(|base| !base.is_empty())
+			.chain(targets)
+			.collect::<Vec<_>>()
+			.join(",")
+	}
+
+	/// Check that every per-target level names a well-formed target
+	///
+	/// Called by [`init_tracing_with_config`](super::init_tracing_with_config) so a typo
+	/// fails loudly instead of the whole filter falling back to `info`.
+	///
+	/// # Errors
+	///
+	/// Returns [`ConfigError::InvalidLogLevel`] naming the first bad directive.
+	pub fn validate_target_levels(&self) -> Result<()> {
+		for (target, level) in &self.target_levels {
+			let well_formed = !target.is_empty()
+				&& target
+					.chars()
+					.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | ':' | '.'));
+			if !well_formed {
+				return Err(
+					ConfigError::InvalidLogLevel { level: format!("{target}={level}") }.into()
+				);
+			}
+		}
+		Ok(())
+	}
+
 	/// Create configuration from environment variables
 	///
 	/// Supported environment variables:
 	/// - `GMN_LOG_LEVEL`: Log level filter (default: "info")
 	/// - `GMN_LOG_FORMAT`: Output format - "pretty", "compact", or "json" (default: "pretty")
-	/// - `GMN_LOG_OUTPUT`: Output target - "stdout", "stderr", or file path (default: "stderr")
-	/// - `GMN_LOG_TIMESTAMPS`: Include timestamps - "true" or "false" (default: "true")
-	/// - `GMN_LOG_THREAD_IDS`: Include thread IDs - "true" or "false" (default: "false")
-	/// - `GMN_LOG_THREAD_NAMES`: Include thread names - "true" or "false" (default: "false")
-	/// - `GMN_LOG_FILE_LINE`: Include file/line info - "true" or "false" (default: "true")
-	/// - `GMN_LOG_SPAN_LIST`: Include span list - "true" or "false" (default: "true")
-	/// - `GMN_LOG_ANSI`: Use ANSI colors - "true" or "false" (default: "true")
+	/// - `GMN_LOG_OUTPUT`: Output target - "stdout", "stderr", "journald", "syslog",
+	///   "syslog:<facility>", or file path (default: "stderr")
+	/// - `GMN_LOG_TIMESTAMPS`: Include timestamps (default: "true")
+	/// - `GMN_LOG_TIME_FORMAT`: Timestamp layout, see `time_format` (default: RFC 3339)
+	/// - `GMN_LOG_THREAD_IDS`: Include thread IDs (default: "false")
+	/// - `GMN_LOG_THREAD_NAMES`: Include thread names (default: "false")
+	/// - `GMN_LOG_FILE_LINE`: Include file/line info (default: "true")
+	/// - `GMN_LOG_SPAN_LIST`: Include span list (default: "true")
+	/// - `GMN_LOG_ANSI`: Use ANSI colors (default: "true")
+	/// - `GMN_LOG_CAPTURE_LOG`: Forward `log` records (default: "true")
+	/// - `GMN_LOG_CHROME_TRACE`: Chrome trace-event output file (default: none)
+	/// - `GMN_LOG_OTLP_ENDPOINT`: OTLP/HTTP traces endpoint (default: none)
+	/// - `GMN_LOG_ROTATION`: Log file rotation - "minutely", "hourly", "daily" or "never"
+	///   (default: "daily")
+	///
+	/// Flags accept "true", "1", "yes" or "on" and "false", "0", "no" or "off", in any
+	/// case. Malformed values are skipped and the default is kept; each one is reported
+	/// with [`display_warning`], since no subscriber is installed yet to log it. Use
+	/// [`try_from_env`](Self::try_from_env) to reject them instead.
 	pub fn from_env() -> Self {
-		let mut config = Self::default();
+		let (config, errors) = Self::default().apply_env(env_var);
+		for error in errors {
+			display_warning(&GmnError::from(error));
+		}
+		config
+	}
 
-		if let Ok(level) = std::env::var("GMN_LOG_LEVEL") {
+	/// Like [`from_env`](Self::from_env), but fail on the first malformed value
+	///
+	/// # Errors
+	///
+	/// Returns [`ConfigError::InvalidLogFormat`] for an unknown `GMN_LOG_FORMAT`, and
+	/// [`ConfigError::EnvVarParse`] for a flag or `GMN_LOG_ROTATION` that does not parse.
+	pub fn try_from_env() -> std::result::Result<Self, ConfigError> {
+		let (config, errors) = Self::default().apply_env(env_var);
+		errors.into_iter().next().map_or(Ok(config), Err)
+	}
+
+	/// Load configuration from a TOML or JSON file, then apply environment overrides
+	///
+	/// The format follows the extension (`.toml` or `.json`). Fields missing from the
+	/// file keep their defaults, and the variables read by [`from_env`](Self::from_env)
+	/// still win over the file.
+	///
+	/// ```toml
+	/// log_level = "gmn_core=debug,info"
+	/// format = "json"
+	/// output = { both = { console = "stderr", file = "logs/app.log" } }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns a [`ConfigError`] if the file cannot be read, has another extension, or
+	/// does not parse, or if an environment override is invalid.
+	pub fn from_file(path: &Path) -> Result<Self> {
+		let shown = path.display().to_string();
+		let contents = std::fs::read_to_string(path)
+			.map_err(|source| ConfigError::FileRead { path: shown.clone(), source })?;
+
+		let parsed = match path.extension().and_then(|ext| ext.to_str()) {
+			Some(ext) if ext.eq_ignore_ascii_case("toml") => {
+				toml::from_str::<Self>(&contents).map_err(|err| err.message().to_string())
+			}
+			Some(ext) if ext.eq_ignore_ascii_case("json") => {
+				serde_json::from_str::<Self>(&contents).map_err(|err| err.to_string())
+			}
+			_ => return Err(ConfigError::UnsupportedFileType { path: shown }.into()),
+		};
+
+		let config = parsed.map_err(|message| ConfigError::FileParse { path: shown, message })?;
+		let (config, errors) = config.apply_env(env_var);
+		errors.into_iter().next().map_or(Ok(config), |error| Err(error.into()))
+	}
+
+	/// Apply the `GMN_LOG_*` variables found by `var` on top of this configuration
+	///
+	/// Malformed values leave their field untouched and are returned as errors.
+	fn apply_env(self, var: impl Fn(&str) -> Option<String>) -> (Self, Vec<ConfigError>) {
+		let mut config = self;
+		let mut errors = Vec::new();
+
+		if let Some(level) = var("GMN_LOG_LEVEL") {
 			config.log_level = level;
 		}
 
-		if let Ok(format) = std::env::var("GMN_LOG_FORMAT") {
-			if let Some(fmt) = LogFormat::from_str(&format) {
-				config.format = fmt;
+		if let Some(format) = var("GMN_LOG_FORMAT") {
+			match LogFormat::from_str(&format) {
+				Some(fmt) => config.format = fmt,
+				None => errors.push(ConfigError::InvalidLogFormat { format }),
 			}
 		}
 
-		if let Ok(output) = std::env::var("GMN_LOG_OUTPUT") {
+		if let Some(output) = var("GMN_LOG_OUTPUT") {
 			config.output = match output.to_lowercase().as_str() {
 				"stdout" => LogOutput::Stdout,
 				"stderr" => LogOutput::Stderr,
-				path => LogOutput::File(PathBuf::from(path)),
+				"journald" => LogOutput::Journald,
+				"syslog" => LogOutput::Syslog { facility: default_syslog_facility() },
+				other => other.strip_prefix("syslog:").map_or_else(
+					|| LogOutput::File(PathBuf::from(&output)),
+					|facility| LogOutput::Syslog { facility: facility.to_string() },
+				),
 			};
 		}
 
-		if let Ok(val) = std::env::var("GMN_LOG_TIMESTAMPS") {
-			config.with_timestamps = val.to_lowercase() == "true";
+		if let Some(format) = var("GMN_LOG_TIME_FORMAT") {
+			config.time_format = Some(format);
 		}
 
-		if let Ok(val) = std::env::var("GMN_LOG_THREAD_IDS") {
-			config.with_thread_ids = val.to_lowercase() == "true";
-		}
-
-		if let Ok(val) = std::env::var("GMN_LOG_THREAD_NAMES") {
-			config.with_thread_names = val.to_lowercase() == "true";
+		let flags = [
+			("GMN_LOG_TIMESTAMPS", &mut config.with_timestamps),
+			("GMN_LOG_THREAD_IDS", &mut config.with_thread_ids),
+			("GMN_LOG_THREAD_NAMES", &mut config.with_thread_names),
+			("GMN_LOG_FILE_LINE", &mut config.with_file_line),
+			("GMN_LOG_SPAN_LIST", &mut config.with_span_list),
+			("GMN_LOG_ANSI", &mut config.with_ansi),
+			("GMN_LOG_CAPTURE_LOG", &mut config.capture_log),
+		];
+		for (name, flag) in flags {
+			if let Some(value) = var(name) {
+				match parse_flag(&value) {
+					Some(enabled) => *flag = enabled,
+					None => errors.push(ConfigError::EnvVarParse { var: name.to_string(), value }),
+				}
+			}
 		}
 
-		if let Ok(val) = std::env::var("GMN_LOG_FILE_LINE") {
-			config.with_file_line = val.to_lowercase() == "true";
+		if let Some(path) = var("GMN_LOG_CHROME_TRACE") {
+			config.chrome_trace = Some(PathBuf::from(path));
 		}
 
-		if let Ok(val) = std::env::var("GMN_LOG_SPAN_LIST") {
-			config.with_span_list = val.to_lowercase() == "true";
+		if let Some(endpoint) = var("GMN_LOG_OTLP_ENDPOINT") {
+			config.otlp_endpoint = Some(endpoint);
 		}
 
-		if let Ok(val) = std::env::var("GMN_LOG_ANSI") {
-			config.with_ansi = val.to_lowercase() == "true";
+		if let Some(value) = var("GMN_LOG_ROTATION") {
+			match Rotation::from_name(&value) {
+				Some(rotation) => config.rotation = rotation,
+				None => errors
+					.push(ConfigError::EnvVarParse { var: "GMN_LOG_ROTATION".to_string(), value }),
+			}
 		}
 
-		config
+		(config, errors)
 	}
 
 	/// Create a preset configuration for development
@@ -179,12 +532,18 @@ impl TracingConfig {
 			log_level: "debug".to_string(),
 			format: LogFormat::Pretty,
 			output: LogOutput::Stderr,
+			rotation: Rotation::Daily,
 			with_timestamps: true,
+			time_format: None,
 			with_thread_ids: false,
 			with_thread_names: false,
 			with_file_line: true,
 			with_span_list: true,
 			with_ansi: true,
+			capture_log: true,
+			chrome_trace: None,
+			otlp_endpoint: None,
+			target_levels: Vec::new(),
 		}
 	}
 
@@ -197,12 +556,18 @@ impl TracingConfig {
 				console: Box::new(LogOutput::Stderr),
 				file: PathBuf::from("logs/gmn.log"),
 			},
+			rotation: Rotation::Daily,
 			with_timestamps: true,
+			time_format: None,
 			with_thread_ids: true,
 			with_thread_names: true,
 			with_file_line: false,
 			with_span_list: false,
 			with_ansi: false,
+			capture_log: true,
+			chrome_trace: None,
+			otlp_endpoint: None,
+			target_levels: Vec::new(),
 		}
 	}
 
@@ -212,12 +577,18 @@ impl TracingConfig {
 			log_level: "warn".to_string(),
 			format: LogFormat::Compact,
 			output: LogOutput::Stderr,
+			rotation: Rotation::Daily,
 			with_timestamps: false,
+			time_format: None,
 			with_thread_ids: false,
 			with_thread_names: false,
 			with_file_line: false,
 			with_span_list: false,
 			with_ansi: false,
+			capture_log: true,
+			chrome_trace: None,
+			otlp_endpoint: None,
+			target_levels: Vec::new(),
 		}
 	}
 
@@ -227,6 +598,28 @@ impl TracingConfig {
 		self
 	}
 
+	/// Builder method to set the level of one target, on top of `log_level`
+	///
+	/// A typed alternative to writing `target=level` directives into the
+	/// [`log_level`](Self::with_log_level) string; setting a target twice keeps the
+	/// last level.
+	///
+	/// ```
+	/// use gmn_core::tracing::TracingConfig;
+	/// use tracing::Level;
+	///
+	/// let config = TracingConfig::new()
+	///     .with_target_level("gmn_core", Level::DEBUG)
+	///     .with_target_level("hyper", Level::WARN);
+	/// assert_eq!(config.filter_directives(), "info,gmn_core=debug,hyper=warn");
+	/// ```
+	#[must_use]
+	pub fn with_target_level(mut self, target: &str, level: Level) -> Self {
+		self.target_levels.retain(|(existing, _)| existing != target);
+		self.target_levels.push((target.to_string(), level));
+		self
+	}
+
 	/// Builder method to set format
 	pub fn with_format(mut self, format: LogFormat) -> Self {
 		self.format = format;
@@ -239,12 +632,26 @@ impl TracingConfig {
 		self
 	}
 
+	/// Builder method to set the log file rotation policy
+	#[must_use]
+	pub const fn with_rotation(mut self, rotation: Rotation) -> Self {
+		self.rotation = rotation;
+		self
+	}
+
 	/// Builder method to enable/disable timestamps
 	pub fn with_timestamps(mut self, enabled: bool) -> Self {
 		self.with_timestamps = enabled;
 		self
 	}
 
+	/// Builder method to set the timestamp layout (see [`time_format`](Self::time_format))
+	#[must_use]
+	pub fn with_time_format(mut self, format: impl Into<String>) -> Self {
+		self.time_format = Some(format.into());
+		self
+	}
+
 	/// Builder method to enable/disable thread IDs
 	pub fn with_thread_ids(mut self, enabled: bool) -> Self {
 		self.with_thread_ids = enabled;
@@ -274,4 +681,200 @@ impl TracingConfig {
 		self.with_ansi = enabled;
 		self
 	}
+
+	/// Builder method to enable/disable forwarding of `log` crate records
+	#[must_use]
+	pub const fn with_capture_log(mut self, enabled: bool) -> Self {
+		self.capture_log = enabled;
+		self
+	}
+
+	/// Builder method to write spans to a Chrome trace-event file
+	#[must_use]
+	pub fn with_chrome_trace(mut self, path: impl Into<PathBuf>) -> Self {
+		self.chrome_trace = Some(path.into());
+		self
+	}
+
+	/// Builder method to export spans to an OTLP/HTTP traces endpoint
+	///
+	/// Only takes effect with the `otel` feature; without it a warning is logged at init.
+	#[must_use]
+	pub fn with_otlp_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+		self.otlp_endpoint = Some(endpoint.into());
+		self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Write `contents` to a fresh temp file with the given extension
+	fn temp_config(extension: &str, contents: &str) -> std::io::Result<PathBuf> {
+		let path =
+			std::env::temp_dir().join(format!("gmn-tracing-{}.{extension}", uuid::Uuid::new_v4()));
+		std::fs::write(&path, contents)?;
+		Ok(path)
+	}
+
+	#[test]
+	fn test_from_file_reads_toml_and_json_outputs()
+	-> std::result::Result<(), Box<dyn std::error::Error>> {
+		let toml_path = temp_config(
+			"toml",
+			r#"
+				log_level = "gmn_core=debug,info"
+				format = "json"
+				output = { both = { console = "stdout", file = "logs/app.log" } }
+			"#,
+		)?;
+		let from_toml = TracingConfig::from_file(&toml_path);
+		std::fs::remove_file(&toml_path)?;
+		let from_toml = from_toml?;
+
+		assert_eq!(from_toml.format, LogFormat::Json);
+		assert_eq!(
+			from_toml.output,
+			LogOutput::Both {
+				console: Box::new(LogOutput::Stdout),
+				file: PathBuf::from("logs/app.log"),
+			}
+		);
+		// Unset fields keep their defaults
+		assert!(from_toml.with_file_line);
+
+		let json_path = temp_config("json", r#"{ "output": { "file": "gmn.log" } }"#)?;
+		let from_json = TracingConfig::from_file(&json_path);
+		std::fs::remove_file(&json_path)?;
+		assert_eq!(from_json?.output, LogOutput::File(PathBuf::from("gmn.log")));
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_file_reports_malformed_and_unsupported_files() -> std::io::Result<()> {
+		let bad = temp_config("toml", "format = 42")?;
+		let err = TracingConfig::from_file(&bad);
+		std::fs::remove_file(&bad)?;
+		assert!(matches!(err, Err(crate::errors::GmnError::Config(ConfigError::FileParse { .. }))));
+
+		let yaml = temp_config("yaml", "format: json")?;
+		let err = TracingConfig::from_file(&yaml);
+		std::fs::remove_file(&yaml)?;
+		assert!(matches!(
+			err,
+			Err(crate::errors::GmnError::Config(ConfigError::UnsupportedFileType { .. }))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_target_levels_render_and_validate() {
+		let config = TracingConfig::new()
+			.with_log_level("warn")
+			.with_target_level("gmn_core::tracing", Level::TRACE)
+			.with_target_level("hyper", Level::INFO)
+			.with_target_level("hyper", Level::ERROR);
+		assert_eq!(config.filter_directives(), "warn,gmn_core::tracing=trace,hyper=error");
+		assert!(config.validate_target_levels().is_ok());
+
+		let typo = TracingConfig::new().with_target_level("gmn_core, hyper", Level::DEBUG);
+		assert!(matches!(
+			typo.validate_target_levels(),
+			Err(crate::errors::GmnError::Config(ConfigError::InvalidLogLevel { .. }))
+		));
+	}
+
+	#[test]
+	fn test_rotation_parses_names_and_deserializes() -> std::result::Result<(), toml::de::Error> {
+		assert_eq!(Rotation::from_name("Hourly"), Some(Rotation::Hourly));
+		assert_eq!(Rotation::from_name("never"), Some(Rotation::Never));
+		assert_eq!(Rotation::from_name("weekly"), None);
+
+		let config: TracingConfig = toml::from_str(r#"rotation = "minutely""#)?;
+		assert_eq!(config.rotation, Rotation::Minutely);
+		assert_eq!(TracingConfig::default().rotation, Rotation::Daily);
+		Ok(())
+	}
+
+	#[test]
+	fn test_env_values_parse_tolerantly_and_report_errors() {
+		let env = std::collections::HashMap::from([
+			("GMN_LOG_TIMESTAMPS", "yes"),
+			("GMN_LOG_ANSI", "OFF"),
+			("GMN_LOG_THREAD_IDS", "maybe"),
+			("GMN_LOG_FORMAT", "yaml"),
+			("GMN_LOG_ROTATION", "hourly"),
+		]);
+		let defaults = TracingConfig::testing();
+		let (config, errors) =
+			defaults.clone().apply_env(|name| env.get(name).map(ToString::to_string));
+
+		assert!(config.with_timestamps);
+		assert!(!config.with_ansi);
+		assert_eq!(config.with_thread_ids, defaults.with_thread_ids);
+		assert_eq!(config.format, defaults.format);
+		assert_eq!(config.rotation, Rotation::Hourly);
+
+		assert_eq!(errors.len(), 2, "{errors:?}");
+		assert!(matches!(&errors[0], ConfigError::InvalidLogFormat { format } if format == "yaml"));
+		assert!(matches!(
+			&errors[1],
+			ConfigError::EnvVarParse { var, value } if var == "GMN_LOG_THREAD_IDS" && value == "maybe"
+		));
+	}
+
+	#[test]
+	fn test_system_log_outputs_deserialize() -> std::result::Result<(), toml::de::Error> {
+		let journald: TracingConfig = toml::from_str(r#"output = "journald""#)?;
+		assert_eq!(journald.output, LogOutput::Journald);
+
+		let syslog: TracingConfig = toml::from_str(r"output = { syslog = {} }")?;
+		assert_eq!(syslog.output, LogOutput::Syslog { facility: "user".to_string() });
+		assert_eq!(syslog.resolved_with(None).system_log.as_deref(), Some("syslog (user)"));
+		Ok(())
+	}
+
+	#[test]
+	fn test_resolved_flattens_output_and_merges_env_filter() {
+		let config = TracingConfig::production().with_log_level("warn");
+		let resolved = config.resolved_with(Some("gmn_core=debug"));
+
+		assert_eq!(resolved.console, Some("stderr"));
+		assert_eq!(resolved.file, Some(PathBuf::from("logs/gmn.log")));
+		assert_eq!(resolved.rotation, Some(Rotation::Daily));
+		assert_eq!(resolved.format, LogFormat::Json);
+		assert!(resolved.log_filter.contains("gmn_core=debug"), "{}", resolved.log_filter);
+		assert!(resolved.log_filter.contains("warn"), "{}", resolved.log_filter);
+
+		let resolved = TracingConfig::new().with_output(LogOutput::Stdout).resolved_with(None);
+		assert_eq!(
+			(resolved.console, resolved.file, resolved.rotation),
+			(Some("stdout"), None, None)
+		);
+		assert_eq!(resolved.log_filter, "info");
+	}
+
+	#[test]
+	fn test_from_env_reports_invalid_values_without_a_subscriber() -> std::io::Result<()> {
+		let output = std::process::Command::new(std::env::current_exe()?)
+			.args(["--exact", "--include-ignored", "--nocapture"])
+			.arg("tracing::config::tests::from_env_with_invalid_format")
+			.env("GMN_LOG_FORMAT", "loud")
+			.output()?;
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		let stderr = String::from_utf8_lossy(&output.stderr);
+
+		assert!(output.status.success(), "{stdout}{stderr}");
+		assert!(stdout.contains("1 passed"), "{stdout}");
+		assert!(stderr.contains("GMN-CFG-002"), "{stderr}");
+		assert!(stderr.contains("loud"), "{stderr}");
+		Ok(())
+	}
+
+	#[test]
+	#[ignore = "run with GMN_LOG_FORMAT=loud by test_from_env_reports_invalid_values_without_a_subscriber"]
+	fn from_env_with_invalid_format() {
+		assert_eq!(TracingConfig::from_env().format, LogFormat::default());
+	}
 }
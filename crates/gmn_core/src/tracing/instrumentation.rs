@@ -3,6 +3,20 @@
 //! This module provides helper macros and functions to simplify instrumentation
 //! of operations, performance measurement, and event logging.
 
+use std::cell::RefCell;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::{LookupSpan, Registry};
+
+use crate::errors::{GenericError, GmnError};
+
+thread_local! {
+	/// Baggage entries pushed by the active [`with_baggage`] scopes, outermost first
+	static BAGGAGE: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
 /// Create a span for an operation with automatic field capture
 ///
 /// # Example
@@ -41,6 +55,32 @@ macro_rules! trace_operation {
 /// ```
 #[macro_export]
 macro_rules! measure_duration {
+	($name:expr, $body:block) => {
+		$crate::measure_duration_timed!($name, $body).0
+	};
+}
+
+/// Measure the duration of an operation, log it, and return it with the block's value
+///
+/// Logs the same line as [`measure_duration!`], then evaluates to
+/// `(value, elapsed)` so the duration can also go to a histogram or a span field.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::measure_duration_timed;
+///
+/// fn load_users(span: &tracing::Span) -> usize {
+///     let (count, elapsed) = measure_duration_timed!("load_users", {
+///         // Your expensive code here
+///         42
+///     });
+///     span.record("duration_ms", elapsed.as_millis());
+///     count
+/// }
+/// ```
+#[macro_export]
+macro_rules! measure_duration_timed {
 	($name:expr, $body:block) => {{
 		let start = std::time::Instant::now();
 		let result = $body;
@@ -50,28 +90,313 @@ macro_rules! measure_duration {
 			duration_ms = duration.as_millis(),
 			"Operation completed"
 		);
+		(result, duration)
+	}};
+}
+
+/// Measure the duration of a block returning a `Result` and log its outcome
+///
+/// Logs `duration_ms` with `outcome = "ok"` at `INFO` level, or with `outcome = "err"`
+/// and the error's `Display` at `WARN` level, then evaluates to the `Result`
+/// unchanged.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::measure_duration_result;
+///
+/// fn read_config() -> std::io::Result<String> {
+///     measure_duration_result!("read_config", { std::fs::read_to_string("app.toml") })
+/// }
+/// ```
+#[macro_export]
+macro_rules! measure_duration_result {
+	($name:expr, $body:block) => {{
+		let start = std::time::Instant::now();
+		let result = $body;
+		let duration = start.elapsed();
+		match &result {
+			Ok(_) => tracing::info!(
+				operation = $name,
+				duration_ms = duration.as_millis(),
+				outcome = "ok",
+				"Operation completed"
+			),
+			Err(err) => tracing::warn!(
+				operation = $name,
+				duration_ms = duration.as_millis(),
+				outcome = "err",
+				error = %err,
+				"Operation failed"
+			),
+		}
+		result
+	}};
+}
+
+/// Measure the wall-clock duration of a future and log it
+///
+/// Evaluates to a new future that must be `.await`ed: it awaits the given future,
+/// logs the same line as [`measure_duration!`], and resolves to the future's output.
+/// The clock starts on the first poll, and the duration includes the time spent
+/// suspended at await points, not just CPU work.
+///
+/// # Example
+///
+/// ```
+/// use gmn_core::measure_duration_async;
+///
+/// async fn fetch_user(id: u64) -> String {
+///     tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+///     format!("user-{id}")
+/// }
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let user = measure_duration_async!("fetch_user", fetch_user(7)).await;
+///     assert_eq!(user, "user-7");
+/// }
+/// ```
+#[macro_export]
+macro_rules! measure_duration_async {
+	($name:expr, $future:expr) => {{
+		// Bind outside the async block so only the future is moved into it
+		let name = $name;
+		let future = $future;
+		async move {
+			let start = std::time::Instant::now();
+			let result = future.await;
+			let duration = start.elapsed();
+			tracing::info!(
+				operation = name,
+				duration_ms = duration.as_millis(),
+				"Operation completed"
+			);
+			result
+		}
+	}};
+}
+
+/// Run an operation and show its outcome to the user
+///
+/// Evaluates to the block's `Result`. On `Ok`, a success frame reading
+/// "`name` completed in `N`ms" is printed (skipped with `quiet = true`); on `Err`, the
+/// error is displayed with [`display_error`](crate::error_display::display_error).
+/// The error type must implement
+/// [`DisplayMetadata`](crate::error_display::DisplayMetadata).
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::{GmnError, operation_reported};
+///
+/// fn sync_accounts() -> Result<usize, GmnError> {
+///     operation_reported!("sync_accounts", {
+///         // Your fallible code here
+///         Ok(42)
+///     })
+/// }
+///
+/// fn warm_cache(quiet: bool) -> Result<(), GmnError> {
+///     operation_reported!("warm_cache", quiet = quiet, { Ok(()) })
+/// }
+/// ```
+#[macro_export]
+macro_rules! operation_reported {
+	($name:expr, $body:block) => {
+		$crate::operation_reported!($name, quiet = false, $body)
+	};
+	($name:expr, quiet = $quiet:expr, $body:block) => {{
+		let start = std::time::Instant::now();
+		let result = $body;
+		$crate::error_display::report_operation($name, start.elapsed(), &result, $quiet);
 		result
 	}};
 }
 
 /// Log an event with context
 ///
+/// The message is a format string, followed by its arguments and then structured
+/// fields. Fields are written as in `tracing`: `key = value` (with an optional `%` or
+/// `?` sigil), or the shorthand `name`, `%name` or `?name` for a variable of the same
+/// name. A bare variable fills a `{}` placeholder until the first field; after it, or
+/// after a `;` separating the arguments from the fields, it is a shorthand field.
+///
 /// # Example
 ///
 /// ```no_run
 /// use gmn_core::log_event;
 ///
-/// fn handle_request() {
+/// fn handle_request(user_id: u64, attempt: u32) {
 ///     log_event!(info, "request_received", request_id = "abc123", method = "GET");
+///     log_event!(info, "user {} logged in", user_id, action = "login");
+///     log_event!(warn, "retry {} of 3", attempt + 1; user_id);
 /// }
 /// ```
 #[macro_export]
 macro_rules! log_event {
-	($level:ident, $message:expr) => {
+	($level:ident, $message:literal $(,)?) => {
 		tracing::$level!($message)
 	};
-	($level:ident, $message:expr, $($field:tt)*) => {
-		tracing::$level!($($field)*, $message)
+	($level:ident, $message:literal; $($rest:tt)+) => {
+		$crate::log_event!(@fields $level, $message, [], [], $($rest)+)
+	};
+	($level:ident, $message:literal, $($rest:tt)+) => {
+		$crate::log_event!(@args $level, $message, [], [], $($rest)+)
+	};
+
+	// Format arguments, up to the first field or `;`
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*], $(,)?) => {
+		tracing::$level!($($fields)* $message, $($args)*)
+	};
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		; $($rest:tt)*) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)*], $($rest)*)
+	};
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$($key:ident).+ = $($rest:tt)*) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)*],
+			$($key).+ = $($rest)*)
+	};
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		% $($rest:tt)*) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)*], % $($rest)*)
+	};
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		? $($rest:tt)*) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)*], ? $($rest)*)
+	};
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$arg:expr ; $($rest:tt)*) => {
+		$crate::log_event!(@fields $level, $message, [$($args)* $arg,], [$($fields)*],
+			$($rest)*)
+	};
+	(@args $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$arg:expr $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@args $level, $message, [$($args)* $arg,], [$($fields)*],
+			$($($rest)*)?)
+	};
+
+	// Structured fields; expressions other than a bare variable are still arguments
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*], $(,)?) => {
+		tracing::$level!($($fields)* $message, $($args)*)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$($key:ident).+ = % $value:expr $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*],
+			[$($fields)* $($key).+ = %$value,], $($($rest)*)?)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$($key:ident).+ = ? $value:expr $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*],
+			[$($fields)* $($key).+ = ?$value,], $($($rest)*)?)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$($key:ident).+ = $value:expr $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*],
+			[$($fields)* $($key).+ = $value,], $($($rest)*)?)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		% $field:ident $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)* %$field,],
+			$($($rest)*)?)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		? $field:ident $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)* ?$field,],
+			$($($rest)*)?)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$field:ident $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)*], [$($fields)* $field,],
+			$($($rest)*)?)
+	};
+	(@fields $level:ident, $message:literal, [$($args:tt)*], [$($fields:tt)*],
+		$arg:expr $(, $($rest:tt)*)?) => {
+		$crate::log_event!(@fields $level, $message, [$($args)* $arg,], [$($fields)*],
+			$($($rest)*)?)
+	};
+}
+
+/// Instrument a database function with the standard database span shape
+///
+/// Wraps the function in `#[tracing::instrument]` with `skip_all`, so arguments are
+/// never captured, and declares the database fields empty: `operation`, `table`,
+/// `rows_affected`, `duration_ms` and `error`. The span is named after the function.
+/// Record the fields from inside with [`tracing::Span::current`].
+///
+/// The calling crate must depend on `tracing` directly, as `#[tracing::instrument]`
+/// refers to it by name.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::instrument_db;
+///
+/// instrument_db! {
+///     /// Load a user's display name
+///     pub fn load_user_name(id: u64) -> String {
+///         let span = tracing::Span::current();
+///         span.record("operation", "SELECT");
+///         span.record("table", "users");
+///         span.record("rows_affected", 1);
+///         format!("user-{id}")
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! instrument_db {
+	($($function:tt)*) => {
+		#[tracing::instrument(
+			skip_all,
+			fields(
+				operation = tracing::field::Empty,
+				table = tracing::field::Empty,
+				rows_affected = tracing::field::Empty,
+				duration_ms = tracing::field::Empty,
+				error = tracing::field::Empty,
+			)
+		)]
+		$($function)*
+	};
+}
+
+/// Instrument an API handler with the standard API request span shape
+///
+/// Like [`instrument_db!`], with the API request fields declared empty: `method`,
+/// `path`, `status_code`, `duration_ms`, `request_id` and `error`.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::instrument_api;
+///
+/// instrument_api! {
+///     async fn get_user(request_id: &str) -> u16 {
+///         let span = tracing::Span::current();
+///         span.record("method", "GET");
+///         span.record("path", "/api/v1/users");
+///         span.record("request_id", request_id);
+///         span.record("status_code", 200);
+///         200
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! instrument_api {
+	($($function:tt)*) => {
+		#[tracing::instrument(
+			skip_all,
+			fields(
+				method = tracing::field::Empty,
+				path = tracing::field::Empty,
+				status_code = tracing::field::Empty,
+				duration_ms = tracing::field::Empty,
+				request_id = tracing::field::Empty,
+				error = tracing::field::Empty,
+			)
+		)]
+		$($function)*
 	};
 }
 
@@ -109,9 +434,422 @@ pub fn record_error_with_context(error: &dyn std::error::Error, context: &str) {
 	tracing::error!(error = %error, context = context, "Error occurred");
 }
 
+/// Build an error with `code` whose context lists the name and recorded fields of `span`
+///
+/// Use it when an operation inside a span fails, to carry what the span knew (table,
+/// path, user, ...) into the error shown to the user. The result is a
+/// [`GenericError::InSpan`] reporting `code`, whose context reads
+/// `span=<name>, key=value, ...`.
+///
+/// Field values are only available when the current subscriber includes a
+/// [`SpanFieldsLayer`]; the subscribers installed by
+/// [`init_tracing`](crate::tracing::init_tracing) do. Otherwise only the span name
+/// is recorded.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::tracing::instrumentation::{db_operation_span, error_with_span_context};
+///
+/// let span = db_operation_span("SELECT", "users");
+/// let _enter = span.enter();
+/// let err = error_with_span_context("GMN-DB-002", &span, "query returned no rows");
+/// assert_eq!(err.code(), "GMN-DB-002");
+/// ```
+pub fn error_with_span_context(
+	code: &'static str,
+	span: &tracing::Span,
+	message: impl Into<String>,
+) -> GmnError {
+	let fields = span
+		.with_subscriber(|(id, dispatch)| {
+			let registry = dispatch.downcast_ref::<Registry>()?;
+			let span = registry.span(id)?;
+			let extensions = span.extensions();
+			extensions.get::<SpanFields>().map(|fields| fields.0.clone())
+		})
+		.flatten()
+		.unwrap_or_default();
+
+	GenericError::InSpan {
+		code,
+		span: span.metadata().map_or("unknown", tracing::Metadata::name).to_string(),
+		fields,
+		message: message.into(),
+	}
+	.into()
+}
+
+/// Layer that keeps every span's recorded fields readable after creation
+///
+/// Tracing hands field values to subscribers only as they are recorded; this layer
+/// stores them in the span's extensions so [`error_with_span_context`] can read them
+/// back later.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpanFieldsLayer;
+
+/// Fields recorded on a span, in recording order, stored in its extensions
+struct SpanFields(Vec<(String, String)>);
+
+/// Visitor writing field values into a [`SpanFields`] list, replacing re-recorded keys
+struct SpanFieldsVisitor<'a>(&'a mut Vec<(String, String)>);
+
+impl SpanFieldsVisitor<'_> {
+	fn insert(&mut self, field: &Field, value: String) {
+		match self.0.iter_mut().find(|(name, _)| name == field.name()) {
+			Some(entry) => entry.1 = value,
+			None => self.0.push((field.name().to_string(), value)),
+		}
+	}
+}
+
+impl Visit for SpanFieldsVisitor<'_> {
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.insert(field, value.to_string());
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+		self.insert(field, format!("{value:?}"));
+	}
+}
+
+impl<S> Layer<S> for SpanFieldsLayer
+where
+	S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+	fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id) {
+			let mut fields = Vec::new();
+			attrs.record(&mut SpanFieldsVisitor(&mut fields));
+			span.extensions_mut().insert(SpanFields(fields));
+		}
+	}
+
+	fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+		if let Some(span) = ctx.span(id)
+			&& let Some(fields) = span.extensions_mut().get_mut::<SpanFields>()
+		{
+			values.record(&mut SpanFieldsVisitor(&mut fields.0));
+		}
+	}
+}
+
+/// Run `f` with key/value baggage attached to everything it does
+///
+/// Baggage is a set of arbitrary tags (tenant, region, feature flag, ...) that is
+/// attached once and inherited by all nested work. Unlike a request or correlation ID,
+/// which identifies a single operation with one value, baggage carries any number of
+/// values and nests: an inner `with_baggage` adds to (and may override keys of) the
+/// outer one.
+///
+/// The closure runs inside a `baggage` span whose `baggage` field holds the merged
+/// tags formatted as `key=value` pairs, so every child span and event is emitted under
+/// it. Use [`current_baggage`] to read the tags programmatically.
+///
+/// Baggage is tracked per thread; work moved to another thread or async task does
+/// not see it through [`current_baggage`], although the span still carries it.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::tracing::instrumentation::{current_baggage, with_baggage};
+///
+/// with_baggage(&[("tenant", "acme"), ("region", "eu-west")], || {
+///     tracing::info!("handled request"); // emitted under tenant=acme,region=eu-west
+///     assert_eq!(current_baggage().len(), 2);
+/// });
+/// ```
+pub fn with_baggage<R>(baggage: &[(&str, &str)], f: impl FnOnce() -> R) -> R {
+	let outer_len = BAGGAGE.with_borrow_mut(|stack| {
+		let len = stack.len();
+		stack.extend(baggage.iter().map(|(key, value)| ((*key).to_string(), (*value).to_string())));
+		len
+	});
+	// Pop this scope's entries even if `f` panics
+	let _scope = BaggageScope { outer_len };
+
+	let merged = current_baggage()
+		.iter()
+		.map(|(key, value)| format!("{key}={value}"))
+		.collect::<Vec<_>>()
+		.join(",");
+	tracing::info_span!("baggage", baggage = %merged).in_scope(f)
+}
+
+/// Baggage visible on the current thread, merged across nested [`with_baggage`] scopes
+///
+/// Keys keep the order in which they were first attached; when a nested scope sets
+/// a key again, the innermost value wins.
+pub fn current_baggage() -> Vec<(String, String)> {
+	BAGGAGE.with_borrow(|stack| {
+		let mut merged: Vec<(String, String)> = Vec::with_capacity(stack.len());
+		for (key, value) in stack {
+			match merged.iter_mut().find(|(existing, _)| existing == key) {
+				Some(entry) => entry.1.clone_from(value),
+				None => merged.push((key.clone(), value.clone())),
+			}
+		}
+		merged
+	})
+}
+
+/// Truncates the thread-local baggage stack back to its size before a scope began
+struct BaggageScope {
+	outer_len: usize,
+}
+
+impl Drop for BaggageScope {
+	fn drop(&mut self) {
+		BAGGAGE.with_borrow_mut(|stack| stack.truncate(self.outer_len));
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use std::sync::{Arc, Mutex};
+	use tracing::field::{Field, Visit};
+	use tracing_subscriber::layer::{Context, SubscriberExt};
+	use tracing_subscriber::registry::LookupSpan;
+
+	/// `baggage` field value recorded on a span
+	struct BaggageField(String);
+
+	impl Visit for BaggageField {
+		fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+			if field.name() == "baggage" {
+				self.0 = format!("{value:?}");
+			}
+		}
+	}
+
+	/// Layer collecting, for every event, the baggage of its enclosing spans
+	#[derive(Clone, Default)]
+	struct BaggageCapture {
+		events: Arc<Mutex<Vec<Vec<String>>>>,
+	}
+
+	impl<S> tracing_subscriber::Layer<S> for BaggageCapture
+	where
+		S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+	{
+		fn on_new_span(
+			&self,
+			attrs: &tracing::span::Attributes<'_>,
+			id: &tracing::span::Id,
+			ctx: Context<'_, S>,
+		) {
+			let mut field = BaggageField(String::new());
+			attrs.record(&mut field);
+			if let Some(span) = ctx.span(id) {
+				span.extensions_mut().insert(field);
+			}
+		}
+
+		fn on_event(&self, event: &tracing::Event<'_>, ctx: Context<'_, S>) {
+			let baggage = ctx
+				.event_scope(event)
+				.into_iter()
+				.flatten()
+				.filter_map(|span| span.extensions().get::<BaggageField>().map(|f| f.0.clone()))
+				.collect();
+			if let Ok(mut events) = self.events.lock() {
+				events.push(baggage);
+			}
+		}
+	}
+
+	#[test]
+	fn test_baggage_propagates_to_child_events() {
+		let capture = BaggageCapture::default();
+		let subscriber = tracing_subscriber::registry().with(capture.clone());
+
+		tracing::subscriber::with_default(subscriber, || {
+			with_baggage(&[("tenant", "acme"), ("region", "eu")], || {
+				let _span = db_operation_span("SELECT", "users").entered();
+				tracing::info!("outer child");
+
+				with_baggage(&[("region", "us"), ("flag", "beta")], || {
+					assert_eq!(
+						current_baggage(),
+						vec![
+							("tenant".to_string(), "acme".to_string()),
+							("region".to_string(), "us".to_string()),
+							("flag".to_string(), "beta".to_string()),
+						]
+					);
+					tracing::info!("inner child");
+				});
+			});
+		});
+
+		assert!(current_baggage().is_empty());
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		assert_eq!(events.len(), 2);
+		assert!(events[0].contains(&"tenant=acme,region=eu".to_string()));
+		assert!(events[1].contains(&"tenant=acme,region=us,flag=beta".to_string()));
+	}
+
+	/// Layer collecting the fields of every event as `name=value` strings, and its level
+	#[derive(Clone, Default)]
+	struct EventCapture {
+		events: Arc<Mutex<Vec<Vec<String>>>>,
+		levels: Arc<Mutex<Vec<tracing::Level>>>,
+	}
+
+	struct EventFields(Vec<String>);
+
+	impl Visit for EventFields {
+		fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+			self.0.push(format!("{}={value:?}", field.name()));
+		}
+
+		fn record_str(&mut self, field: &Field, value: &str) {
+			self.0.push(format!("{}={value}", field.name()));
+		}
+	}
+
+	impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for EventCapture {
+		fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+			let mut fields = EventFields(Vec::new());
+			event.record(&mut fields);
+			if let Ok(mut events) = self.events.lock() {
+				events.push(fields.0);
+			}
+			if let Ok(mut levels) = self.levels.lock() {
+				levels.push(*event.metadata().level());
+			}
+		}
+	}
+
+	#[test]
+	fn test_log_event_separates_format_arguments_from_fields() {
+		let capture = EventCapture::default();
+		let subscriber = tracing_subscriber::registry().with(capture.clone());
+
+		tracing::subscriber::with_default(subscriber, || {
+			let user_id = 42;
+			let role = "admin";
+			crate::log_event!(info, "request_received");
+			crate::log_event!(info, "request_received", request_id = "abc123", method = "GET");
+			crate::log_event!(info, "user {user_id} logged in", action = "login");
+			crate::log_event!(info, "user {} logged in", user_id, action = "login");
+			crate::log_event!(
+				warn,
+				"user {user_id} is {}",
+				role.to_uppercase(),
+				ip = %"10.0.0.1",
+				retry = ?Some(2)
+			);
+		});
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		assert_eq!(events[0], ["message=request_received"]);
+		assert_eq!(events[1], ["message=request_received", "request_id=abc123", "method=GET"]);
+		assert_eq!(events[2], ["message=user 42 logged in", "action=login"]);
+		assert_eq!(events[3], ["message=user 42 logged in", "action=login"]);
+		assert_eq!(events[4], ["message=user 42 is ADMIN", "ip=10.0.0.1", "retry=Some(2)"]);
+	}
+
+	#[test]
+	fn test_log_event_accepts_shorthand_fields() {
+		let capture = EventCapture::default();
+		let subscriber = tracing_subscriber::registry().with(capture.clone());
+
+		tracing::subscriber::with_default(subscriber, || {
+			let user_id = 42;
+			let role = "admin";
+			let retry = Some(2);
+			crate::log_event!(info, "request_received"; user_id);
+			crate::log_event!(info, "attempt {}", 1 + 1, ?retry, %role, user_id);
+			crate::log_event!(info, "attempt {} by {}", 3, user_id; role);
+		});
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		assert_eq!(events[0], ["message=request_received", "user_id=42"]);
+		assert_eq!(events[1], ["message=attempt 2", "retry=Some(2)", "role=admin", "user_id=42"]);
+		assert_eq!(events[2], ["message=attempt 3 by 42", "role=admin"]);
+	}
+
+	#[test]
+	fn test_measure_duration_timed_returns_value_and_elapsed() {
+		let (value, elapsed) = crate::measure_duration_timed!("sleep", {
+			std::thread::sleep(std::time::Duration::from_millis(5));
+			"done"
+		});
+		assert_eq!(value, "done");
+		assert!(elapsed >= std::time::Duration::from_millis(5));
+
+		assert_eq!(crate::measure_duration!("add", { 1 + 1 }), 2);
+	}
+
+	#[test]
+	fn test_measure_duration_result_logs_the_outcome() {
+		let capture = EventCapture::default();
+		let subscriber = tracing_subscriber::registry().with(capture.clone());
+
+		tracing::subscriber::with_default(subscriber, || {
+			let ok: Result<u8, String> = crate::measure_duration_result!("load", { Ok(1) });
+			assert_eq!(ok, Ok(1));
+
+			let err: Result<u8, String> =
+				crate::measure_duration_result!("save", { Err("disk full".to_string()) });
+			assert_eq!(err, Err("disk full".to_string()));
+		});
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		let levels = capture.levels.lock().map(|levels| levels.clone()).unwrap_or_default();
+		assert_eq!(levels, [tracing::Level::INFO, tracing::Level::WARN]);
+
+		let [ok, err] = events.as_slice() else { panic!("expected two events: {events:?}") };
+		assert!(ok.contains(&"operation=load".to_string()), "{ok:?}");
+		assert!(ok.contains(&"outcome=ok".to_string()), "{ok:?}");
+		assert!(ok.iter().any(|field| field.starts_with("duration_ms=")), "{ok:?}");
+		assert!(!ok.iter().any(|field| field.starts_with("error=")), "{ok:?}");
+
+		assert!(err.contains(&"operation=save".to_string()), "{err:?}");
+		assert!(err.contains(&"outcome=err".to_string()), "{err:?}");
+		assert!(err.contains(&"error=disk full".to_string()), "{err:?}");
+		assert!(err.iter().any(|field| field.starts_with("duration_ms=")), "{err:?}");
+	}
+
+	#[tokio::test(flavor = "current_thread")]
+	async fn test_measure_duration_async_resolves_to_the_output() {
+		let delay = std::time::Duration::from_millis(5);
+		let measured = crate::measure_duration_async!("sleep", async move {
+			tokio::time::sleep(delay).await;
+			3
+		});
+		assert_eq!(measured.await, 3);
+	}
+
+	crate::instrument_db! {
+		fn instrumented_query(_password: &str) -> Option<(String, Vec<String>)> {
+			tracing::Span::current().metadata().map(|metadata| {
+				let fields = metadata.fields().iter().map(|field| field.name().to_string());
+				(metadata.name().to_string(), fields.collect())
+			})
+		}
+	}
+
+	crate::instrument_api! {
+		fn instrumented_handler() -> Option<usize> {
+			tracing::Span::current().metadata().map(|metadata| metadata.fields().len())
+		}
+	}
+
+	#[test]
+	fn test_instrument_presets_apply_the_domain_span_shape() {
+		tracing::subscriber::with_default(tracing_subscriber::registry(), || {
+			let (name, fields) = instrumented_query("hunter2").unwrap_or_default();
+			assert_eq!(name, "instrumented_query");
+			// Arguments are skipped, so only the declared fields exist
+			assert_eq!(fields, ["operation", "table", "rows_affected", "duration_ms", "error"]);
+
+			assert_eq!(instrumented_handler(), Some(6));
+		});
+	}
 
 	#[test]
 	fn test_span_creation() {
@@ -130,4 +868,22 @@ mod tests {
 			assert_eq!(metadata.name(), "api_request");
 		}
 	}
+
+	#[test]
+	fn test_error_with_span_context_lists_span_fields() {
+		let subscriber = tracing_subscriber::registry().with(SpanFieldsLayer);
+
+		let err = tracing::subscriber::with_default(subscriber, || {
+			let span = db_operation_span("SELECT", "users");
+			let _enter = span.enter();
+			error_with_span_context("GMN-DB-002", &span, "query returned no rows")
+		});
+
+		assert_eq!(err.code(), "GMN-DB-002");
+		assert_eq!(err.to_string(), "Generic error: query returned no rows");
+		assert_eq!(
+			err.context().as_deref(),
+			Some("span=db_operation, operation=SELECT, table=users")
+		);
+	}
 }
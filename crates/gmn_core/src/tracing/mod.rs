@@ -3,10 +3,18 @@
 //! This module provides comprehensive observability with structured logging,
 //! tracing configuration, and instrumentation utilities.
 
+pub mod chrome;
 pub mod config;
 pub mod instrumentation;
+#[cfg(feature = "otel")]
+pub mod otel;
 pub mod setup;
+#[cfg(feature = "syslog")]
+mod syslog_writer;
 
 // Re-exports for convenience
-pub use config::TracingConfig;
-pub use setup::{init_tracing, init_tracing_with_config};
+pub use config::{ResolvedTracingConfig, Rotation, TracingConfig};
+pub use setup::{
+	ReloadHandle, ScopedTracingGuard, TracingGuard, init_tracing, init_tracing_with_config,
+	set_default_with_config,
+};
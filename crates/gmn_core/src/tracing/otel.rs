@@ -0,0 +1,62 @@
+//! OpenTelemetry export over OTLP (requires the `otel` feature).
+//!
+//! When [`TracingConfig::otlp_endpoint`](super::TracingConfig::otlp_endpoint) is set,
+//! initialization adds a `tracing-opentelemetry` layer next to the console and file
+//! layers, and spans are batched and sent over OTLP/HTTP on a background thread. Span
+//! fields become span attributes, so the domain span helpers need no changes.
+//!
+//! The service name comes from `OTEL_SERVICE_NAME` (or `OTEL_RESOURCE_ATTRIBUTES`), as
+//! with any OpenTelemetry SDK. The last batch is sent when the
+//! [`TracingGuard`](super::TracingGuard) is dropped.
+
+use crate::errors::{Result, TracingError};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+
+/// Instrumentation scope reported with every exported span
+const SCOPE_NAME: &str = env!("CARGO_PKG_NAME");
+
+/// Build a tracer exporting spans to `endpoint`, with the provider that owns its batches
+///
+/// `endpoint` is the full OTLP/HTTP traces URL, e.g. `http://localhost:4318/v1/traces`.
+/// Nothing is sent until the first batch is full or flushed, so an unreachable
+/// collector does not fail here.
+pub(super) fn otlp_tracer(endpoint: &str) -> Result<(SdkTracer, SdkTracerProvider)> {
+	let exporter = opentelemetry_otlp::SpanExporter::builder()
+		.with_http()
+		.with_endpoint(endpoint)
+		.build()
+		.map_err(|source| TracingError::OtlpFailed {
+			endpoint: endpoint.to_string(),
+			source: source.into(),
+		})?;
+
+	let provider = SdkTracerProvider::builder().with_batch_exporter(exporter).build();
+	Ok((provider.tracer(SCOPE_NAME), provider))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use opentelemetry::trace::TraceContextExt;
+	use tracing_opentelemetry::OpenTelemetrySpanExt;
+	use tracing_subscriber::layer::SubscriberExt;
+
+	#[test]
+	fn test_spans_flow_into_the_otlp_layer() -> Result<()> {
+		// Port 9 (discard) keeps the exporter from reaching a real collector
+		let (tracer, provider) = otlp_tracer("http://127.0.0.1:9/v1/traces")?;
+		let subscriber =
+			tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+		tracing::subscriber::with_default(subscriber, || {
+			let span = tracing::info_span!("db.query", db.table = "users");
+			assert!(span.context().has_active_span());
+		});
+
+		// Export fails against the discard port; only shutting down cleanly matters here
+		let _ = provider.shutdown();
+		Ok(())
+	}
+}
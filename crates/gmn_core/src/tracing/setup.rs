@@ -3,22 +3,202 @@
 //! This module provides functions to initialize the global tracing subscriber
 //! with various configuration options.
 //!
-//! The implementation uses helper functions to avoid exponential match growth
-//! while maintaining type safety and avoiding unnecessary boxing overhead.
+//! The global subscriber can only be set once per process. Tests that each want
+//! their own subscriber should use [`set_default_with_config`] instead, which
+//! installs it for the current thread until the returned guard is dropped.
+//!
+//! The implementation uses helper functions to avoid exponential match growth:
+//! every output gets its `fmt` layers from [`fmt_layer`], which applies the
+//! config's `with_*` flags once and boxes the format-specific result.
 
-use super::config::{LogFormat, LogOutput, TracingConfig};
-use crate::errors::{Result, TracingError};
+use super::chrome::{ChromeTraceGuard, ChromeTraceLayer};
+use super::config::{LogFormat, LogOutput, Rotation, TracingConfig};
+use super::instrumentation::SpanFieldsLayer;
+use crate::error_display::display_config_change;
+use crate::errors::{ConfigError, Result, TracingError};
+#[cfg(feature = "otel")]
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
 use std::sync::atomic::{AtomicBool, Ordering};
-use tracing_subscriber::{EnvFilter, Layer, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+use time::format_description::OwnedFormatItem;
+use tracing::Dispatch;
+use tracing::dispatcher::DefaultGuard;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::RollingFileAppender;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::fmt::format::{DefaultFields, Format, Full};
+use tracing_subscriber::fmt::time::{FormatTime, UtcTime};
+use tracing_subscriber::registry::{LookupSpan, Registry};
+use tracing_subscriber::{EnvFilter, Layer, fmt, layer::SubscriberExt, reload};
 
 /// Global flag to track if tracing has been initialized
 static TRACING_INITIALIZED: AtomicBool = AtomicBool::new(false);
 
+/// Layers added to every subscriber, whatever its output and format
+struct Extras {
+	chrome_trace: Option<ChromeTraceLayer>,
+	#[cfg(feature = "otel")]
+	otlp_tracer: Option<SdkTracer>,
+}
+
+/// A subscriber built from a [`TracingConfig`], with the guards of the files it writes
+struct Built {
+	dispatch: Dispatch,
+	reload: ReloadHandle,
+	file_guard: Option<WorkerGuard>,
+	chrome_guard: Option<ChromeTraceGuard>,
+	#[cfg(feature = "otel")]
+	otlp_provider: Option<SdkTracerProvider>,
+}
+
+/// Level filter of every subscriber, swappable through a [`ReloadHandle`]
+type ReloadableFilter = reload::Layer<EnvFilter, Registry>;
+
+/// Changes the level filter of an installed subscriber at runtime
+///
+/// Obtained from [`TracingGuard::reload_handle`] or
+/// [`ScopedTracingGuard::reload_handle`]. Handles are cheap to clone, so one can be
+/// moved into a `SIGHUP` handler or an admin endpoint while the guard stays in `main`.
+#[derive(Debug, Clone)]
+pub struct ReloadHandle {
+	inner: reload::Handle<EnvFilter, Registry>,
+}
+
+impl ReloadHandle {
+	/// Replace the level filter with `directives`, e.g. `"debug"` or `"info,gmn_core=trace"`
+	///
+	/// The new filter replaces the whole previous one, including directives that came
+	/// from `RUST_LOG` at init. Nothing is printed; see
+	/// [`set_level_reported`](Self::set_level_reported) to show the change to the user.
+	///
+	/// # Errors
+	///
+	/// Returns [`ConfigError::InvalidLogLevel`] if `directives` does not parse, or
+	/// [`TracingError::ReloadFailed`] if the subscriber is gone.
+	pub fn set_level(&self, directives: &str) -> Result<()> {
+		let filter = EnvFilter::try_new(directives)
+			.map_err(|_| ConfigError::InvalidLogLevel { level: directives.to_string() })?;
+		self.inner.reload(filter).map_err(|source| TracingError::ReloadFailed { source })?;
+		Ok(())
+	}
+
+	/// [`set_level`](Self::set_level), then confirm the change on stderr with
+	/// [`display_config_change`](crate::error_display::display_config_change)
+	///
+	/// Meant for interactive changes, e.g. a `/loglevel` command in a REPL.
+	///
+	/// # Errors
+	///
+	/// Same as [`set_level`](Self::set_level); nothing is displayed on error.
+	pub fn set_level_reported(&self, directives: &str) -> Result<()> {
+		let old = self.current_level().unwrap_or_default();
+		self.set_level(directives)?;
+		display_config_change("log_level", &old, directives);
+		Ok(())
+	}
+
+	/// The level filter currently applied, or `None` if the subscriber is gone
+	pub fn current_level(&self) -> Option<String> {
+		self.inner.with_current(ToString::to_string).ok()
+	}
+}
+
+/// Keeps the global subscriber installed by [`init_tracing_with_config`] working
+///
+/// Holds the [`WorkerGuard`] of the log file writer, if any, which flushes it when
+/// dropped, and hands out [`ReloadHandle`]s. Dropping it also closes the Chrome trace
+/// file and sends the last batch of spans to the OTLP collector, when configured.
+#[must_use = "dropping the guard stops file logging"]
+#[derive(Debug)]
+pub struct TracingGuard {
+	reload: ReloadHandle,
+	_file_guard: Option<WorkerGuard>,
+	chrome_guard: Option<ChromeTraceGuard>,
+	#[cfg(feature = "otel")]
+	otlp: Option<(String, SdkTracerProvider)>,
+}
+
+impl TracingGuard {
+	/// A handle to change the level filter at runtime
+	pub fn reload_handle(&self) -> ReloadHandle {
+		self.reload.clone()
+	}
+
+	/// Close the Chrome trace file now rather than when the guard is dropped
+	///
+	/// Writes the end of the JSON array and flushes it. Spans closed afterwards are not
+	/// recorded. Does nothing when no Chrome trace was configured or it is closed already.
+	///
+	/// # Errors
+	///
+	/// Returns an error if writing to the trace file fails.
+	pub fn finish_chrome_trace(&mut self) -> std::io::Result<()> {
+		self.chrome_guard.take().map_or(Ok(()), ChromeTraceGuard::finish)
+	}
+
+	/// Send the spans still batched for the OTLP collector now and stop the exporter
+	///
+	/// Spans closed afterwards are not exported. Does nothing when no OTLP endpoint was
+	/// configured or the exporter is stopped already.
+	///
+	/// # Errors
+	///
+	/// Returns [`TracingError::OtlpFailed`] if the final export fails.
+	#[cfg(feature = "otel")]
+	pub fn shutdown_otlp(&mut self) -> Result<()> {
+		let Some((endpoint, provider)) = self.otlp.take() else {
+			return Ok(());
+		};
+		provider
+			.shutdown()
+			.map_err(|source| TracingError::OtlpFailed { endpoint, source: source.into() })?;
+		Ok(())
+	}
+}
+
+#[cfg(feature = "otel")]
+impl Drop for TracingGuard {
+	fn drop(&mut self) {
+		// The global subscriber keeps a tracer, so the provider never shuts down by itself
+		if let Err(error) = self.shutdown_otlp() {
+			tracing::warn!(%error, "Failed to export the last spans");
+		}
+	}
+}
+
+/// Subscriber installed by [`set_default_with_config`] for the current thread
+///
+/// Dropping it restores the previous default and flushes the log and Chrome trace
+/// files, if any.
+#[must_use = "the subscriber is uninstalled when the guard is dropped"]
+#[derive(Debug)]
+pub struct ScopedTracingGuard {
+	reload: ReloadHandle,
+	// Uninstall the subscriber before flushing its files
+	_default: DefaultGuard,
+	_file_guard: Option<WorkerGuard>,
+	_chrome_guard: Option<ChromeTraceGuard>,
+	#[cfg(feature = "otel")]
+	_otlp_provider: Option<SdkTracerProvider>,
+}
+
+impl ScopedTracingGuard {
+	/// A handle to change the level filter of this subscriber
+	pub fn reload_handle(&self) -> ReloadHandle {
+		self.reload.clone()
+	}
+}
+
 /// Initialize tracing with default configuration
 ///
 /// This is a convenience function that initializes tracing with configuration
 /// loaded from environment variables. If no environment variables are set,
-/// it uses sensible defaults.
+/// it uses sensible defaults. A malformed value is reported and its default is used
+/// instead, as with [`TracingConfig::from_env`].
+///
+/// The [`TracingGuard`] is leaked so file logging keeps working for the whole process;
+/// lines still buffered at exit may be lost, a Chrome trace is never closed, and the
+/// level cannot be reloaded. Use [`init_tracing_with_config`] and hold the guard to
+/// avoid that.
 ///
 /// # Errors
 ///
@@ -40,13 +220,19 @@ static TRACING_INITIALIZED: AtomicBool = AtomicBool::new(false);
 /// ```
 pub fn init_tracing() -> Result<()> {
 	let config = TracingConfig::from_env();
-	init_tracing_with_config(config)
+	std::mem::forget(init_tracing_with_config(config)?);
+	Ok(())
 }
 
 /// Initialize tracing with a custom configuration
 ///
 /// This function sets up the global tracing subscriber based on the provided
-/// configuration. It can only be called once per process.
+/// configuration. It can only be called once per process. Once installed, it logs
+/// the effective configuration (see [`TracingConfig::resolved`]) at `info` level.
+///
+/// The config's `log_level` is the base filter; directives from `RUST_LOG` are
+/// merged on top of it, so `RUST_LOG=gmn_core=debug` raises `gmn_core` to debug
+/// while every other target keeps the configured level.
 ///
 /// # Errors
 ///
@@ -54,6 +240,20 @@ pub fn init_tracing() -> Result<()> {
 /// - Tracing has already been initialized
 /// - Failed to create log file (if file output is configured)
 /// - Failed to set the global subscriber
+/// - Failed to install the `log` bridge (if `capture_log` is enabled)
+/// - Failed to create the Chrome trace file (if `chrome_trace` is set)
+///
+/// Only the first call in a process can install the global subscriber; see
+/// [`set_default_with_config`] for a per-thread alternative suited to tests.
+///
+/// File output goes through a background writer thread so logging never blocks the
+/// caller. The returned [`TracingGuard`] flushes it when dropped: hold it for the
+/// lifetime of the process, typically as a binding in `main`. Dropping it early stops
+/// file logging. Its [`reload_handle`](TracingGuard::reload_handle) changes the level
+/// filter without a restart.
+///
+/// Dropping the guard also closes the Chrome trace file, if `chrome_trace` is set, and
+/// sends the last spans to the OTLP collector, if `otlp_endpoint` is set.
 ///
 /// # Example
 ///
@@ -63,158 +263,421 @@ pub fn init_tracing() -> Result<()> {
 ///
 /// fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let config = TracingConfig::development();
-///     init_tracing_with_config(config)?;
+///     let guard = init_tracing_with_config(config)?;
 ///     tracing::info!("Application started in development mode");
+///
+///     // Later, e.g. from a SIGHUP handler
+///     guard.reload_handle().set_level("debug")?;
 ///     Ok(())
 /// }
 /// ```
-pub fn init_tracing_with_config(config: TracingConfig) -> Result<()> {
+pub fn init_tracing_with_config(config: TracingConfig) -> Result<TracingGuard> {
 	// Check if already initialized
 	if TRACING_INITIALIZED.swap(true, Ordering::SeqCst) {
 		return Err(TracingError::AlreadyInitialized.into());
 	}
 
-	// Create the env filter from the log level
-	let env_filter = EnvFilter::try_from_default_env()
-		.or_else(|_| EnvFilter::try_new(&config.log_level))
-		.unwrap_or_else(|_| EnvFilter::new("info"));
+	// A failed init installs nothing, so it must not block a corrected retry
+	install_global(&config).inspect_err(|_| TRACING_INITIALIZED.store(false, Ordering::SeqCst))
+}
 
-	// Dispatch to appropriate initialization function based on output type
-	// This avoids exponential match growth by separating concerns
-	match config.output {
-		LogOutput::Stdout => init_stdout(env_filter, config.format),
-		LogOutput::Stderr => init_stderr(env_filter, config.format),
-		LogOutput::File(ref path) => init_file(env_filter, config.format, path),
-		LogOutput::Both { ref console, ref file } => {
-			init_both(env_filter, config.format, console, file)
-		}
+/// Build the subscriber described by `config` and install it as the global default
+fn install_global(config: &TracingConfig) -> Result<TracingGuard> {
+	let built = build(config)?;
+	// Before the subscriber: if another `log` logger is installed, nothing is changed
+	if config.capture_log {
+		bridge_log_records()?;
 	}
+	tracing::dispatcher::set_global_default(built.dispatch)
+		.map_err(|source| TracingError::SetGlobalFailed { source })?;
 
-	Ok(())
+	// First line of every log: what was actually applied
+	tracing::info!(config = ?config.resolved(), "Tracing initialized");
+	#[cfg(not(feature = "otel"))]
+	if let Some(endpoint) = &config.otlp_endpoint {
+		tracing::warn!(
+			endpoint,
+			"OTLP endpoint ignored: gmn_core was built without the `otel` feature"
+		);
+	}
+	Ok(TracingGuard {
+		reload: built.reload,
+		_file_guard: built.file_guard,
+		chrome_guard: built.chrome_guard,
+		#[cfg(feature = "otel")]
+		otlp: config.otlp_endpoint.clone().zip(built.otlp_provider),
+	})
 }
 
-/// Initialize tracing with stdout output
-fn init_stdout(env_filter: EnvFilter, format: LogFormat) {
-	match format {
-		LogFormat::Pretty => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(std::io::stdout)
-				.pretty()
-				.init();
-		}
-		LogFormat::Compact => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(std::io::stdout)
-				.compact()
-				.init();
+/// Install a subscriber built from `config` for the current thread only
+///
+/// The test-friendly counterpart of [`init_tracing_with_config`]: it can be called
+/// any number of times, does not count as initialization for [`is_initialized`], and
+/// the subscriber stays active until the returned guard is dropped. The `log` bridge
+/// is process-global, so `capture_log` is ignored here.
+///
+/// # Errors
+///
+/// Returns an error if a per-target level is malformed or the log or Chrome trace
+/// file cannot be created.
+///
+/// # Example
+///
+/// ```no_run
+/// use gmn_core::tracing::{TracingConfig, set_default_with_config};
+///
+/// #[test]
+/// fn logs_at_debug() -> gmn_core::Result<()> {
+///     let _tracing = set_default_with_config(&TracingConfig::testing().with_log_level("debug"))?;
+///     tracing::debug!("only seen by this test's subscriber");
+///     Ok(())
+/// }
+/// ```
+pub fn set_default_with_config(config: &TracingConfig) -> Result<ScopedTracingGuard> {
+	let built = build(config)?;
+	Ok(ScopedTracingGuard {
+		reload: built.reload,
+		_default: tracing::dispatcher::set_default(&built.dispatch),
+		_file_guard: built.file_guard,
+		_chrome_guard: built.chrome_guard,
+		#[cfg(feature = "otel")]
+		_otlp_provider: built.otlp_provider,
+	})
+}
+
+/// Forget that [`init_tracing_with_config`] was called, so it can be called again
+///
+/// Meant for test harnesses that check [`is_initialized`]. The global subscriber
+/// itself cannot be replaced once set, so a later init still fails, with
+/// [`TracingError::SetGlobalFailed`] instead of `AlreadyInitialized`; prefer
+/// [`set_default_with_config`] for per-test subscribers.
+#[cfg(any(test, feature = "test-util"))]
+pub fn reset_for_tests() {
+	TRACING_INITIALIZED.store(false, Ordering::SeqCst);
+}
+
+/// Build the subscriber described by `config` without installing it
+fn build(config: &TracingConfig) -> Result<Built> {
+	// Create the env filter from the log level, with RUST_LOG layered on top
+	config.validate_target_levels()?;
+	let env_directives = std::env::var(EnvFilter::DEFAULT_ENV).ok();
+	let env_filter = build_env_filter(&config.filter_directives(), env_directives.as_deref());
+	let (filter, reload) = reload::Layer::new(env_filter);
+
+	let (chrome_trace, chrome_guard) = match config.chrome_trace {
+		Some(ref path) => {
+			let (layer, guard) = ChromeTraceLayer::new(path).map_err(|source| {
+				TracingError::FileCreationFailed { path: path.display().to_string(), source }
+			})?;
+			(Some(layer), Some(guard))
 		}
-		LogFormat::Json => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(std::io::stdout)
-				.json()
-				.init();
+		None => (None, None),
+	};
+	#[cfg(feature = "otel")]
+	let (otlp_tracer, otlp_provider) = match config.otlp_endpoint {
+		Some(ref endpoint) => {
+			let (tracer, provider) = super::otel::otlp_tracer(endpoint)?;
+			(Some(tracer), Some(provider))
 		}
-	}
-}
+		None => (None, None),
+	};
+	let extras = Extras {
+		chrome_trace,
+		#[cfg(feature = "otel")]
+		otlp_tracer,
+	};
 
-/// Initialize tracing with stderr output
-fn init_stderr(env_filter: EnvFilter, format: LogFormat) {
-	match format {
-		LogFormat::Pretty => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(std::io::stderr)
-				.pretty()
-				.init();
+	// Dispatch to appropriate build function based on output type
+	// This avoids exponential match growth by separating concerns
+	let options = FmtOptions::from_config(config)?;
+	let (dispatch, file_guard) = match config.output {
+		LogOutput::Stdout => (build_stdout(filter, &options, extras), None),
+		LogOutput::Stderr => (build_stderr(filter, &options, extras), None),
+		LogOutput::File(ref path) => {
+			let appender = file_appender(path, config.rotation)?;
+			let (dispatch, guard) = build_file(filter, &options, appender, extras);
+			(dispatch, Some(guard))
 		}
-		LogFormat::Compact => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(std::io::stderr)
-				.compact()
-				.init();
+		LogOutput::Both { ref console, ref file } => {
+			let appender = file_appender(file, config.rotation)?;
+			let (dispatch, guard) = build_both(filter, &options, console, appender, extras);
+			(dispatch, Some(guard))
 		}
-		LogFormat::Json => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(std::io::stderr)
-				.json()
-				.init();
+		LogOutput::Journald => (build_journald(filter, extras)?, None),
+		LogOutput::Syslog { ref facility } => {
+			(build_syslog(filter, &options, facility, extras)?, None)
 		}
-	}
+	};
+
+	Ok(Built {
+		dispatch,
+		reload: ReloadHandle { inner: reload },
+		file_guard,
+		chrome_guard,
+		#[cfg(feature = "otel")]
+		otlp_provider,
+	})
 }
 
-/// Initialize tracing with file output
-fn init_file(env_filter: EnvFilter, format: LogFormat, path: &std::path::PathBuf) {
-	let file_appender = tracing_appender::rolling::daily(
-		path.parent().unwrap_or(std::path::Path::new(".")),
-		path.file_name().unwrap_or(std::ffi::OsStr::new("gmn.log")),
-	);
+/// Wrap `subscriber` into a [`Dispatch`] with the layers every configuration gets
+///
+/// A [`SpanFieldsLayer`] is added on top so span fields stay readable for
+/// [`error_with_span_context`](super::instrumentation::error_with_span_context), along
+/// with the Chrome trace and OpenTelemetry layers when they are configured.
+fn into_dispatch<S>(subscriber: S, extras: Extras) -> Dispatch
+where
+	S: tracing::Subscriber + for<'a> LookupSpan<'a> + Send + Sync + 'static,
+{
+	let subscriber = subscriber.with(SpanFieldsLayer).with(extras.chrome_trace);
+	#[cfg(feature = "otel")]
+	let subscriber = subscriber
+		.with(extras.otlp_tracer.map(|tracer| tracing_opentelemetry::layer().with_tracer(tracer)));
+	Dispatch::new(subscriber)
+}
 
-	match format {
-		LogFormat::Pretty => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(file_appender)
-				.with_ansi(false)
-				.pretty()
-				.init();
-		}
-		LogFormat::Compact => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(file_appender)
-				.with_ansi(false)
-				.compact()
-				.init();
+/// Forward records emitted through the `log` crate to the current tracing subscriber
+///
+/// The bridge passes every record through and leaves level filtering to the
+/// subscriber's `EnvFilter`, so `log` and `tracing` events obey the same directives.
+#[cfg(feature = "log-compat")]
+fn bridge_log_records() -> Result<()> {
+	tracing_log::LogTracer::init().map_err(|source| TracingError::LogBridgeFailed { source })?;
+	Ok(())
+}
+
+/// Without the `log-compat` feature there is no bridge to install
+#[cfg(not(feature = "log-compat"))]
+#[allow(clippy::unnecessary_wraps)]
+const fn bridge_log_records() -> Result<()> {
+	Ok(())
+}
+
+/// Build the level filter from the configured directives plus environment overrides.
+///
+/// `base` (the config's `log_level`) is parsed first and falls back to `info` when it is
+/// not a valid directive. Each directive in `env_directives` (usually `RUST_LOG`) is then
+/// added on top: a directive for a target already present in `base` replaces it, while
+/// targets the base does not mention keep their base level. Unparsable env directives
+/// are skipped rather than discarding the whole filter.
+pub(super) fn build_env_filter(base: &str, env_directives: Option<&str>) -> EnvFilter {
+	let mut filter = EnvFilter::try_new(base).unwrap_or_else(|_| EnvFilter::new("info"));
+
+	for directive in env_directives.unwrap_or_default().split(',').map(str::trim) {
+		if directive.is_empty() {
+			continue;
 		}
-		LogFormat::Json => {
-			tracing_subscriber::fmt()
-				.with_env_filter(env_filter)
-				.with_writer(file_appender)
-				.json()
-				.init();
+		if let Ok(directive) = directive.parse() {
+			filter = filter.add_directive(directive);
 		}
 	}
+
+	filter
+}
+
+/// Open the log file at `path`, rotated according to `rotation`
+///
+/// Rotated files are named after `path` with a date suffix; `path` itself is used as
+/// is for [`Rotation::Never`].
+fn file_appender(path: &std::path::Path, rotation: Rotation) -> Result<RollingFileAppender> {
+	RollingFileAppender::builder()
+		.rotation(rotation.into())
+		.filename_prefix(path.file_name().and_then(|name| name.to_str()).unwrap_or("gmn.log"))
+		.build(path.parent().unwrap_or_else(|| std::path::Path::new(".")))
+		.map_err(|err| {
+			TracingError::FileCreationFailed {
+				path: path.display().to_string(),
+				source: std::io::Error::other(err),
+			}
+			.into()
+		})
 }
 
-/// Initialize tracing with both console and file output
-fn init_both(
-	env_filter: EnvFilter,
+/// How `fmt` layers render events, from the `with_*` flags of a [`TracingConfig`]
+///
+/// `format` and `ansi` start from the config and are overridden for log files.
+#[derive(Clone)]
+struct FmtOptions<'a> {
+	config: &'a TracingConfig,
 	format: LogFormat,
+	ansi: bool,
+	/// `None` when timestamps are off
+	timer: Option<Timer>,
+}
+
+/// Timestamp source of the `fmt` layers
+#[derive(Clone)]
+enum Timer {
+	/// RFC 3339 with microseconds, the `tracing-subscriber` default
+	Default,
+	/// UTC time in the configured `time_format`
+	Custom(UtcTime<OwnedFormatItem>),
+}
+
+impl<'a> FmtOptions<'a> {
+	fn from_config(config: &'a TracingConfig) -> Result<Self> {
+		let timer = match (&config.time_format, config.with_timestamps) {
+			(_, false) => None,
+			(None, true) => Some(Timer::Default),
+			(Some(format), true) => {
+				let items = time::format_description::parse_owned::<2>(format).map_err(|err| {
+					ConfigError::InvalidTimeFormat {
+						format: format.clone(),
+						message: err.to_string(),
+					}
+				})?;
+				Some(Timer::Custom(UtcTime::new(items)))
+			}
+		};
+
+		Ok(Self { config, format: config.format, ansi: config.with_ansi, timer })
+	}
+
+	/// The same options for a log file: never colored
+	fn for_file(&self) -> Self {
+		Self { ansi: false, ..self.clone() }
+	}
+}
+
+/// Build the `fmt` layer writing to `writer` as described by `options`
+fn fmt_layer<S, W>(options: &FmtOptions<'_>, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+	S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+	W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+	let layer = fmt::layer()
+		.with_writer(writer)
+		.with_ansi(options.ansi)
+		.with_thread_ids(options.config.with_thread_ids)
+		.with_thread_names(options.config.with_thread_names)
+		.with_file(options.config.with_file_line)
+		.with_line_number(options.config.with_file_line);
+
+	match &options.timer {
+		None => with_format(layer.without_time(), options),
+		Some(Timer::Default) => with_format(layer, options),
+		Some(Timer::Custom(timer)) => with_format(layer.with_timer(timer.clone()), options),
+	}
+}
+
+/// Apply the output format to a `fmt` layer whose timer is already chosen
+fn with_format<S, T, W>(
+	layer: fmt::Layer<S, DefaultFields, Format<Full, T>, W>,
+	options: &FmtOptions<'_>,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+	S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+	T: FormatTime + Send + Sync + 'static,
+	W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+	match options.format {
+		LogFormat::Pretty => layer.pretty().boxed(),
+		LogFormat::Compact => layer.compact().boxed(),
+		LogFormat::Json => layer.json().with_span_list(options.config.with_span_list).boxed(),
+	}
+}
+
+/// Build a subscriber writing to stdout
+fn build_stdout(filter: ReloadableFilter, options: &FmtOptions<'_>, extras: Extras) -> Dispatch {
+	let layer = fmt_layer(options, std::io::stdout);
+	into_dispatch(tracing_subscriber::registry().with(filter).with(layer), extras)
+}
+
+/// Build a subscriber writing to stderr
+fn build_stderr(filter: ReloadableFilter, options: &FmtOptions<'_>, extras: Extras) -> Dispatch {
+	let layer = fmt_layer(options, std::io::stderr);
+	into_dispatch(tracing_subscriber::registry().with(filter).with(layer), extras)
+}
+
+/// Build a subscriber writing to a log file
+fn build_file(
+	filter: ReloadableFilter,
+	options: &FmtOptions<'_>,
+	appender: RollingFileAppender,
+	extras: Extras,
+) -> (Dispatch, WorkerGuard) {
+	let (file_appender, guard) = tracing_appender::non_blocking(appender);
+	let layer = fmt_layer(&options.for_file(), file_appender);
+
+	(into_dispatch(tracing_subscriber::registry().with(filter).with(layer), extras), guard)
+}
+
+/// Build a subscriber writing to both the console and a log file
+fn build_both(
+	filter: ReloadableFilter,
+	options: &FmtOptions<'_>,
 	console: &LogOutput,
-	file: &std::path::PathBuf,
-) {
-	let file_appender = tracing_appender::rolling::daily(
-		file.parent().unwrap_or(std::path::Path::new(".")),
-		file.file_name().unwrap_or(std::ffi::OsStr::new("gmn.log")),
-	);
+	appender: RollingFileAppender,
+	extras: Extras,
+) -> (Dispatch, WorkerGuard) {
+	let (file_appender, guard) = tracing_appender::non_blocking(appender);
 
-	// Create console layer based on console output type and format
-	// Using .boxed() method which properly implements the Layer trait
-	let console_layer = match (console, format) {
-		(LogOutput::Stdout, LogFormat::Pretty) => {
-			fmt::layer().with_writer(std::io::stdout).pretty().boxed()
-		}
-		(LogOutput::Stdout, LogFormat::Compact) => {
-			fmt::layer().with_writer(std::io::stdout).compact().boxed()
-		}
-		(LogOutput::Stdout, LogFormat::Json) => {
-			fmt::layer().with_writer(std::io::stdout).json().boxed()
-		}
-		// Default to stderr for any other console output type
-		(_, LogFormat::Pretty) => fmt::layer().with_writer(std::io::stderr).pretty().boxed(),
-		(_, LogFormat::Compact) => fmt::layer().with_writer(std::io::stderr).compact().boxed(),
-		(_, LogFormat::Json) => fmt::layer().with_writer(std::io::stderr).json().boxed(),
+	// Default to stderr for any console output type other than stdout
+	let console_layer = match console {
+		LogOutput::Stdout => fmt_layer(options, std::io::stdout),
+		_ => fmt_layer(options, std::io::stderr),
 	};
 
-	// File layer always uses JSON for structured logging
-	let file_layer = fmt::layer().with_writer(file_appender).json();
+	// File layer uses the same format as the console, without colors
+	let file_layer = fmt_layer(&options.for_file(), file_appender);
+
+	let dispatch = into_dispatch(
+		tracing_subscriber::registry().with(filter).with(console_layer).with(file_layer),
+		extras,
+	);
+
+	(dispatch, guard)
+}
+
+/// Build a subscriber sending events to the systemd journal
+#[cfg(feature = "journald")]
+fn build_journald(filter: ReloadableFilter, extras: Extras) -> Result<Dispatch> {
+	let layer = tracing_journald::layer().map_err(|source| TracingError::OutputUnavailable {
+		output: "journald".to_string(),
+		source: source.into(),
+	})?;
+	Ok(into_dispatch(tracing_subscriber::registry().with(filter).with(layer), extras))
+}
+
+/// Without the `journald` feature there is no journal layer
+#[cfg(not(feature = "journald"))]
+fn build_journald(_filter: ReloadableFilter, _extras: Extras) -> Result<Dispatch> {
+	Err(missing_feature("journald"))
+}
 
-	tracing_subscriber::registry().with(env_filter).with(console_layer).with(file_layer).init();
+/// Build a subscriber sending events to the local syslog daemon
+#[cfg(feature = "syslog")]
+fn build_syslog(
+	filter: ReloadableFilter,
+	options: &FmtOptions<'_>,
+	facility: &str,
+	extras: Extras,
+) -> Result<Dispatch> {
+	let writer = super::syslog_writer::SyslogMakeWriter::connect(facility)?;
+	// The daemon stamps messages itself and does not render colors
+	let options = FmtOptions { ansi: false, timer: None, ..options.clone() };
+	let layer = fmt_layer(&options, writer);
+	Ok(into_dispatch(tracing_subscriber::registry().with(filter).with(layer), extras))
+}
+
+/// Without the `syslog` feature there is no syslog writer
+#[cfg(not(feature = "syslog"))]
+fn build_syslog(
+	_filter: ReloadableFilter,
+	_options: &FmtOptions<'_>,
+	_facility: &str,
+	_extras: Extras,
+) -> Result<Dispatch> {
+	Err(missing_feature("syslog"))
+}
+
+/// Error for an output whose cargo feature is disabled
+#[cfg(any(not(feature = "journald"), not(feature = "syslog")))]
+fn missing_feature(output: &str) -> crate::errors::GmnError {
+	TracingError::OutputUnavailable {
+		output: output.to_string(),
+		source: format!("gmn_core was built without the `{output}` feature").into(),
+	}
+	.into()
 }
 
 /// Check if tracing has been initialized
@@ -227,6 +690,268 @@ pub fn is_initialized() -> bool {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use std::sync::{Arc, Mutex, PoisonError};
+	use tracing::Level;
+	use tracing_subscriber::layer::Context;
+
+	/// Layer recording the target and level of every event that passes the filter.
+	#[derive(Clone, Default)]
+	struct CapturingLayer {
+		events: Arc<Mutex<Vec<(String, Level)>>>,
+	}
+
+	impl<S: tracing::Subscriber> Layer<S> for CapturingLayer {
+		fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+			// Bridged `log` records carry their original target in fields
+			#[cfg(feature = "log-compat")]
+			let normalized = tracing_log::NormalizeEvent::normalized_metadata(event);
+			#[cfg(feature = "log-compat")]
+			let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+			#[cfg(not(feature = "log-compat"))]
+			let metadata = event.metadata();
+			if let Ok(mut events) = self.events.lock() {
+				events.push((metadata.target().to_string(), *metadata.level()));
+			}
+		}
+	}
+
+	#[test]
+	fn test_env_directives_merge_over_base() {
+		let capture = CapturingLayer::default();
+		let filter = build_env_filter("warn", Some("gmn_core=debug"));
+		let subscriber = tracing_subscriber::registry().with(filter).with(capture.clone());
+
+		tracing::subscriber::with_default(subscriber, || {
+			tracing::debug!(target: "gmn_core::tracing", "core debug");
+			tracing::info!(target: "other_crate", "other info");
+			tracing::warn!(target: "other_crate", "other warn");
+		});
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		assert_eq!(
+			events,
+			vec![
+				("gmn_core::tracing".to_string(), Level::DEBUG),
+				("other_crate".to_string(), Level::WARN),
+			]
+		);
+	}
+
+	#[test]
+	fn test_env_directive_replaces_base_for_same_target() {
+		let capture = CapturingLayer::default();
+		let filter =
+			build_env_filter("info,gmn_core=trace", Some("gmn_core=error,not a directive"));
+		let subscriber = tracing_subscriber::registry().with(filter).with(capture.clone());
+
+		tracing::subscriber::with_default(subscriber, || {
+			tracing::warn!(target: "gmn_core", "core warn");
+			tracing::info!(target: "other_crate", "other info");
+		});
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		assert_eq!(events, vec![("other_crate".to_string(), Level::INFO)]);
+	}
+
+	#[cfg(feature = "log-compat")]
+	#[test]
+	fn test_log_records_reach_tracing() {
+		// The `log` bridge is process-global, so this is the only test that installs it
+		assert!(bridge_log_records().is_ok());
+
+		let capture = CapturingLayer::default();
+		let subscriber = tracing_subscriber::registry().with(capture.clone());
+		tracing::subscriber::with_default(subscriber, || {
+			tracing_log::log::warn!(target: "legacy_dep", "emitted through the log crate");
+		});
+
+		let events = capture.events.lock().map(|events| events.clone()).unwrap_or_default();
+		assert!(events.contains(&("legacy_dep".to_string(), Level::WARN)), "{events:?}");
+	}
+
+	#[test]
+	fn test_set_default_with_config_is_scoped_and_repeatable() -> Result<()> {
+		for _ in 0..2 {
+			let _tracing =
+				set_default_with_config(&TracingConfig::testing().with_log_level("debug"))?;
+			assert!(tracing::enabled!(Level::DEBUG));
+		}
+		assert!(!tracing::enabled!(Level::ERROR));
+		Ok(())
+	}
+
+	/// Writer appending into a shared buffer, to inspect what a `fmt` layer printed
+	#[derive(Clone, Default)]
+	struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+	impl std::io::Write for SharedBuffer {
+		fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+			self.0.lock().map_err(|_| std::io::Error::other("poisoned"))?.extend_from_slice(buf);
+			Ok(buf.len())
+		}
+
+		fn flush(&mut self) -> std::io::Result<()> {
+			Ok(())
+		}
+	}
+
+	/// Emit one event through a `fmt` layer built from `config` and return its output
+	fn render_event(config: &TracingConfig) -> Result<String> {
+		let buffer = SharedBuffer::default();
+		let writer = buffer.clone();
+		let layer = fmt_layer(&FmtOptions::from_config(config)?, move || writer.clone());
+		tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+			tracing::info!("rendered");
+		});
+		let bytes = buffer.0.lock().map(|bytes| bytes.clone()).unwrap_or_default();
+		Ok(String::from_utf8_lossy(&bytes).into_owned())
+	}
+
+	#[test]
+	fn test_custom_time_format_is_applied_and_validated() -> Result<()> {
+		let config = TracingConfig::new()
+			.with_format(LogFormat::Compact)
+			.with_ansi(false)
+			.with_time_format("[year]|[month]|[day]T");
+		let output = render_event(&config)?;
+		let year = chrono::Utc::now().format("%Y|").to_string();
+		assert!(output.starts_with(&year), "{output}");
+
+		let invalid = TracingConfig::new().with_time_format("[hour");
+		assert!(matches!(
+			FmtOptions::from_config(&invalid),
+			Err(crate::errors::GmnError::Config(ConfigError::InvalidTimeFormat { .. }))
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn test_with_flags_reach_the_fmt_layer() -> Result<()> {
+		let plain = TracingConfig::new()
+			.with_format(LogFormat::Compact)
+			.with_ansi(false)
+			.with_timestamps(false)
+			.with_file_line(false);
+		let output = render_event(&plain)?;
+		assert!(output.starts_with(" INFO"), "{output}");
+		assert!(!output.contains('\x1b') && !output.contains("setup.rs"), "{output}");
+
+		let detailed = plain.with_ansi(true).with_thread_ids(true).with_file_line(true);
+		let output = render_event(&detailed)?;
+		assert!(output.contains('\x1b'), "{output}");
+		assert!(output.contains("ThreadId("), "{output}");
+		assert!(output.contains("setup.rs"), "{output}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_log_files_are_never_colored() -> Result<()> {
+		let config = TracingConfig::production().with_ansi(true);
+		let options = FmtOptions::from_config(&config)?;
+		assert!(options.ansi && !options.for_file().ansi);
+		Ok(())
+	}
+
+	#[test]
+	fn test_both_output_writes_the_file_in_the_configured_format()
+	-> std::result::Result<(), Box<dyn std::error::Error>> {
+		let dir = std::env::temp_dir().join(format!("gmn-both-{}", uuid::Uuid::new_v4()));
+		std::fs::create_dir_all(&dir)?;
+		let config = TracingConfig::testing()
+			.with_log_level("info")
+			.with_format(LogFormat::Pretty)
+			.with_rotation(Rotation::Never)
+			.with_output(LogOutput::Both {
+				console: Box::new(LogOutput::Stderr),
+				file: dir.join("app.log"),
+			});
+
+		let tracing = set_default_with_config(&config)?;
+		tracing::info!(user = "ada", "pretty in the file too");
+		drop(tracing);
+
+		let contents = std::fs::read_to_string(dir.join("app.log"));
+		std::fs::remove_dir_all(&dir)?;
+		let contents = contents?;
+		assert!(contents.contains("pretty in the file too"), "{contents}");
+		assert!(serde_json::from_str::<serde_json::Value>(contents.trim()).is_err(), "{contents}");
+		assert!(!contents.contains('\x1b'), "{contents}");
+		Ok(())
+	}
+
+	#[test]
+	fn test_reload_handle_swaps_the_level() -> Result<()> {
+		let tracing = set_default_with_config(&TracingConfig::testing())?;
+		let handle = tracing.reload_handle();
+		assert!(!tracing::enabled!(Level::DEBUG));
+
+		handle.set_level("debug")?;
+		assert!(tracing::enabled!(Level::DEBUG));
+		assert_eq!(handle.current_level().as_deref(), Some("debug"));
+
+		assert!(handle.set_level("gmn_core=loud").is_err());
+		assert!(handle.set_level_reported("gmn_core=loud").is_err());
+		assert_eq!(handle.current_level().as_deref(), Some("debug"));
+		Ok(())
+	}
+
+	/// Serializes the tests that read or write [`TRACING_INITIALIZED`]
+	static INIT_FLAG: Mutex<()> = Mutex::new(());
+
+	#[test]
+	fn test_failed_init_can_be_retried() {
+		let _flag = INIT_FLAG.lock().unwrap_or_else(PoisonError::into_inner);
+		let typo = || TracingConfig::new().with_target_level("gmn_core, hyper", Level::DEBUG);
+
+		for _ in 0..2 {
+			assert!(matches!(
+				init_tracing_with_config(typo()),
+				Err(crate::errors::GmnError::Config(ConfigError::InvalidLogLevel { .. }))
+			));
+		}
+		assert!(!is_initialized());
+	}
+
+	#[test]
+	fn test_reset_for_tests_clears_initialized_flag() {
+		let _flag = INIT_FLAG.lock().unwrap_or_else(PoisonError::into_inner);
+		TRACING_INITIALIZED.store(true, Ordering::SeqCst);
+		reset_for_tests();
+		assert!(!is_initialized());
+	}
+
+	#[test]
+	fn test_dropping_the_guard_closes_the_chrome_trace()
+	-> std::result::Result<(), Box<dyn std::error::Error>> {
+		let path = std::env::temp_dir().join(format!("gmn-chrome-{}.json", uuid::Uuid::new_v4()));
+		let output = std::process::Command::new(std::env::current_exe()?)
+			.args(["--exact", "--include-ignored"])
+			.arg("tracing::setup::tests::init_with_chrome_trace")
+			.env("GMN_TEST_CHROME_TRACE", &path)
+			.output()?;
+		let stdout = String::from_utf8_lossy(&output.stdout);
+		assert!(output.status.success(), "{stdout}{}", String::from_utf8_lossy(&output.stderr));
+		assert!(stdout.contains("1 passed"), "{stdout}");
+
+		let contents = std::fs::read_to_string(&path);
+		std::fs::remove_file(&path)?;
+		let events: Vec<serde_json::Value> = serde_json::from_str(&contents?)?;
+		assert!(events.iter().any(|event| event["ph"] == "X" && event["name"] == "load_config"));
+		Ok(())
+	}
+
+	#[test]
+	#[ignore = "run in its own process by test_dropping_the_guard_closes_the_chrome_trace"]
+	fn init_with_chrome_trace() -> Result<()> {
+		let Some(path) = std::env::var_os("GMN_TEST_CHROME_TRACE") else {
+			return Ok(());
+		};
+		let config = TracingConfig::testing().with_log_level("info").with_chrome_trace(path);
+		let guard = init_tracing_with_config(config)?;
+		tracing::info_span!("load_config").in_scope(|| tracing::info!("loaded"));
+		drop(guard);
+		Ok(())
+	}
 
 	#[test]
 	fn test_is_initialized() {
@@ -0,0 +1,146 @@
+//! Syslog output (requires the `syslog` feature).
+//!
+//! [`SyslogMakeWriter`] plugs into a `fmt` layer like any other writer: each event is
+//! formatted as usual, then sent to the local syslog daemon as one RFC 3164 message
+//! whose severity follows the event's level. The daemon adds its own timestamp.
+
+use crate::errors::{Result, TracingError};
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex, PoisonError};
+use syslog::{Facility, Formatter3164, Logger, LoggerBackend};
+use tracing::{Level, Metadata};
+use tracing_subscriber::fmt::MakeWriter;
+
+type SyslogLogger = Logger<LoggerBackend, Formatter3164>;
+
+/// Writer factory sending each formatted event to syslog
+#[derive(Clone)]
+pub(super) struct SyslogMakeWriter {
+	logger: Arc<Mutex<SyslogLogger>>,
+}
+
+impl SyslogMakeWriter {
+	/// Connect to the local syslog daemon, logging under `facility` (e.g. `"daemon"`)
+	pub(super) fn connect(facility: &str) -> Result<Self> {
+		let formatter = formatter(facility)?;
+		syslog::unix(formatter).map(Self::new).map_err(|err| unavailable(&err))
+	}
+
+	/// Connect to the syslog socket at `path` instead of the system default
+	#[cfg(test)]
+	fn connect_to(facility: &str, path: &std::path::Path) -> Result<Self> {
+		let formatter = formatter(facility)?;
+		syslog::unix_custom(formatter, path).map(Self::new).map_err(|err| unavailable(&err))
+	}
+
+	fn new(logger: SyslogLogger) -> Self {
+		Self { logger: Arc::new(Mutex::new(logger)) }
+	}
+
+	fn line(&self, level: Level) -> SyslogLine<'_> {
+		SyslogLine { logger: &self.logger, level, buf: Vec::new() }
+	}
+}
+
+impl<'a> MakeWriter<'a> for SyslogMakeWriter {
+	type Writer = SyslogLine<'a>;
+
+	fn make_writer(&'a self) -> Self::Writer {
+		self.line(Level::INFO)
+	}
+
+	fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+		self.line(*meta.level())
+	}
+}
+
+/// One event being formatted; sent to syslog when dropped
+pub(super) struct SyslogLine<'a> {
+	logger: &'a Mutex<SyslogLogger>,
+	level: Level,
+	buf: Vec<u8>,
+}
+
+impl Write for SyslogLine<'_> {
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.buf.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+impl Drop for SyslogLine<'_> {
+	fn drop(&mut self) {
+		let message = String::from_utf8_lossy(&self.buf);
+		let message = message.trim_end();
+		if message.is_empty() {
+			return;
+		}
+
+		let mut logger = self.logger.lock().unwrap_or_else(PoisonError::into_inner);
+		// A missing syslog daemon must never break the traced program
+		let _ = match self.level {
+			Level::ERROR => logger.err(message),
+			Level::WARN => logger.warning(message),
+			Level::INFO => logger.info(message),
+			_ => logger.debug(message),
+		};
+	}
+}
+
+/// RFC 3164 formatter tagging messages with this process
+fn formatter(facility: &str) -> Result<Formatter3164> {
+	let parsed: Facility = facility.parse().map_err(|()| TracingError::OutputUnavailable {
+		output: "syslog".to_string(),
+		source: format!("unknown facility {facility:?}").into(),
+	})?;
+	let process = std::env::current_exe()
+		.ok()
+		.and_then(|exe| exe.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+		.unwrap_or_else(|| env!("CARGO_PKG_NAME").to_string());
+
+	Ok(Formatter3164 { facility: parsed, hostname: None, process, pid: std::process::id() })
+}
+
+fn unavailable(err: &syslog::Error) -> crate::errors::GmnError {
+	TracingError::OutputUnavailable { output: "syslog".to_string(), source: err.to_string().into() }
+		.into()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::os::unix::net::UnixDatagram;
+	use tracing_subscriber::layer::SubscriberExt;
+
+	#[test]
+	fn test_events_reach_syslog_with_their_severity()
+	-> std::result::Result<(), Box<dyn std::error::Error>> {
+		let path = std::env::temp_dir().join(format!("gmn-syslog-{}.sock", uuid::Uuid::new_v4()));
+		let daemon = UnixDatagram::bind(&path)?;
+		let writer = SyslogMakeWriter::connect_to("local0", &path);
+		std::fs::remove_file(&path)?;
+
+		let layer = tracing_subscriber::fmt::layer()
+			.with_writer(writer?)
+			.with_ansi(false)
+			.without_time()
+			.compact();
+		tracing::subscriber::with_default(tracing_subscriber::registry().with(layer), || {
+			tracing::error!(disk = "sda1", "disk full");
+		});
+
+		let mut datagram = [0; 1024];
+		let len = daemon.recv(&mut datagram)?;
+		let message = String::from_utf8_lossy(&datagram[..len]);
+		// local0 (16) * 8 + err (3)
+		assert!(message.starts_with("<131>"), "{message}");
+		assert!(message.contains("disk full") && message.contains("disk=\"sda1\""), "{message}");
+
+		assert!(SyslogMakeWriter::connect("nonsense").is_err());
+		Ok(())
+	}
+}